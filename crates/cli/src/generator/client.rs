@@ -53,6 +53,15 @@ pub fn generate(args: &GenerateArgs) -> TokenStream {
         }
     });
 
+    let log_slow_queries_fn = cfg!(feature = "tracing").then(|| {
+        quote! {
+            /// Logs each query or batch that takes at least `threshold` to execute
+            pub fn log_slow_queries(self, threshold: ::std::time::Duration) -> Self {
+                self.with_slow_query_callback(threshold, #pcr::log_slow_query)
+            }
+        }
+    });
+
     let mock_ctor = cfg!(feature = "mocking").then(|| {
         quote! {
             pub fn _mock() -> (Self, #pcr::MockStore) {
@@ -93,17 +102,42 @@ pub fn generate(args: &GenerateArgs) -> TokenStream {
         },
     };
 
+    let postgres_fns = (args.connector.name() == psl::builtin_connectors::POSTGRES.name())
+        .then(|| {
+            quote! {
+                /// Must be called on the client of an interactive transaction
+                pub async fn _advisory_lock(&self, key: i64) -> #pcr::Result<()> {
+                    #pcr::advisory_lock(&self.0, key, super::DATABASE_STR).await
+                }
+
+                /// Must be called on the client of an interactive transaction
+                pub async fn _try_advisory_lock(&self, key: i64) -> #pcr::Result<bool> {
+                    #pcr::try_advisory_lock(&self.0, key, super::DATABASE_STR).await
+                }
+
+                pub async fn _defer_constraints(&self, constraints: &[&str]) -> #pcr::Result<()> {
+                    #pcr::defer_constraints(&self.0, constraints).await
+                }
+
+                pub async fn _check_constraints_immediately(&self, constraints: &[&str]) -> #pcr::Result<()> {
+                    #pcr::check_constraints_immediately(&self.0, constraints).await
+                }
+            }
+        });
+
     quote! {
         pub struct PrismaClientBuilder {
             url: Option<String>,
             action_notifier: #pcr::ActionNotifier,
+            connect_options: #pcr::ConnectOptions,
         }
 
         impl PrismaClientBuilder {
             fn new() -> Self {
                 Self {
                     url: None,
-                    action_notifier: #pcr::ActionNotifier::new()
+                    action_notifier: #pcr::ActionNotifier::new(),
+                    connect_options: Default::default(),
                 }
             }
 
@@ -112,13 +146,60 @@ pub fn generate(args: &GenerateArgs) -> TokenStream {
                 self
             }
 
+            /// Sets how long to wait for each attempt to connect to the database
+            pub fn with_connect_timeout(mut self, timeout: ::std::time::Duration) -> Self {
+                self.connect_options.timeout = Some(timeout);
+                self
+            }
+
+            /// Sets how many times to retry connecting to the database, waiting longer between each attempt
+            pub fn with_connect_retries(mut self, retries: u32) -> Self {
+                self.connect_options.retries = retries;
+                self
+            }
+
+            /// Adds a read replica that queries outside of transactions will be sent to,
+            /// taking turns with any other replicas
+            pub fn with_replica_url(mut self, url: String) -> Self {
+                self.connect_options.replica_urls.push(url);
+                self
+            }
+
+            /// Doesn't connect to the database until the first query is executed
+            pub fn with_lazy_connection(mut self) -> Self {
+                self.connect_options.lazy = true;
+                self
+            }
+
+            /// Calls `callback` after each query or batch is executed
+            pub fn with_query_callback(mut self, callback: impl Fn(&#pcr::QueryEvent) + 'static + Send + Sync) -> Self {
+                self.action_notifier.query_callbacks.push(Box::new(callback));
+                self
+            }
+
+            /// Calls `callback` after each query or batch that takes at least `threshold` to execute
+            pub fn with_slow_query_callback(
+                self,
+                threshold: ::std::time::Duration,
+                callback: impl Fn(&#pcr::QueryEvent) + 'static + Send + Sync
+            ) -> Self {
+                self.with_query_callback(move |event| {
+                    if event.duration >= threshold {
+                        callback(event)
+                    }
+                })
+            }
+
+            #log_slow_queries_fn
+
             #callback_fn
 
             pub async fn build(self) -> Result<PrismaClient, #pcr::NewClientError> {
                 let internals = #pcr::PrismaClientInternals::new(
                     self.url,
                     self.action_notifier,
-                    super::DATAMODEL_STR
+                    super::DATAMODEL_STR,
+                    self.connect_options
                 ).await?;
 
                 Ok(PrismaClient(internals))
@@ -143,10 +224,37 @@ pub fn generate(args: &GenerateArgs) -> TokenStream {
 
             #raw_queries
 
+            #postgres_fns
+
             pub async fn _batch<'batch, T: #pcr::BatchContainer<'batch, Marker>, Marker>(&self, queries: T) -> #pcr::Result<<T as #pcr::BatchContainer<'batch, Marker>>::ReturnType> {
                 #pcr::batch(queries, &self.0).await
             }
 
+            pub async fn _batch_with_isolation_level<'batch, T: #pcr::BatchContainer<'batch, Marker>, Marker>(
+                &self,
+                queries: T,
+                isolation_level: impl #pcr::TransactionIsolationLevel
+            ) -> #pcr::Result<<T as #pcr::BatchContainer<'batch, Marker>>::ReturnType> {
+                #pcr::batch_with_isolation_level(queries, &self.0, Some(isolation_level.to_string())).await
+            }
+
+            pub async fn _batch_without_transaction<'a, Q: #pcr::Query<'a>>(
+                &self,
+                queries: impl IntoIterator<Item = Q>
+            ) -> #pcr::Result<Vec<#pcr::Result<Q::ReturnValue>>> {
+                #pcr::batch_without_transaction(queries, &self.0).await
+            }
+
+            pub async fn _ping(&self) -> #pcr::Result<()> {
+                self.0.ping().await
+            }
+
+            /// Creates a client that sends all queries to the primary database instead of any replicas,
+            /// for reading data immediately after it has been written
+            pub fn _primary(&self) -> Self {
+                Self(self.0.with_primary())
+            }
+
             pub fn _transaction(&self) -> #pcr::TransactionBuilder<Self> {
                 #pcr::TransactionBuilder::_new(self, &self.0)
             }
@@ -13,6 +13,7 @@ use prisma_client_rust_sdk::{
 pub fn scalar_selections_fn(
     comp_type: CompositeTypeWalker,
     module_path: &TokenStream,
+    args: &GenerateArgs,
 ) -> TokenStream {
     let pcr = quote!(::prisma_client_rust);
 
@@ -21,7 +22,7 @@ pub fn scalar_selections_fn(
 
         Some(match field.r#type() {
             ScalarFieldType::BuiltInScalar(_) | ScalarFieldType::Enum(_) => {
-                field.type_tokens(module_path)?;
+                field.type_tokens(module_path, args.bigint_type)?;
                 quote!(#pcr::sel(#field_name_snake::NAME))
             }
             ScalarFieldType::CompositeType(id) => {
@@ -56,15 +57,15 @@ pub fn modules(args: &GenerateArgs, module_path: &TokenStream) -> Vec<TokenStrea
         .map(|comp_type| {
             let comp_type_name_snake = snake_ident(comp_type.name());
 
-            let scalar_selections_fn = scalar_selections_fn(comp_type, module_path);
+            let scalar_selections_fn = scalar_selections_fn(comp_type, module_path, args);
 
-            let data_struct = data::struct_definition(comp_type);
+            let data_struct = data::struct_definition(comp_type, args);
             let order_by_enum = order_by::enum_definition(comp_type, args);
-            let create_fn = set_params::create_fn(comp_type);
+            let create_fn = set_params::create_fn(comp_type, args);
 
             let parts = CompositeTypeModulePart::combine(vec![
-                set_params::module_part(comp_type),
-                where_params::module_part(comp_type),
+                set_params::module_part(comp_type, args),
+                where_params::module_part(comp_type, args),
             ]);
 
             quote! {
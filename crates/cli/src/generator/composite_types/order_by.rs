@@ -70,6 +70,7 @@ pub fn enum_definition(comp_type: CompositeTypeWalker, args: &GenerateArgs) -> T
                         &quote!(super::),
                         &FieldArity::Required,
                         &args.schema.db,
+                        args.bigint_type,
                     )?;
 
                     let pv = match &typ_ref.location {
@@ -99,6 +100,7 @@ pub fn enum_definition(comp_type: CompositeTypeWalker, args: &GenerateArgs) -> T
                                     &quote!(),
                                     &FieldArity::Required,
                                     &args.schema.db,
+                                    args.bigint_type,
                                 )?,
                                 quote! {
                                 impl From<Order> for super::OrderByWithRelationParam {
@@ -115,7 +117,7 @@ pub fn enum_definition(comp_type: CompositeTypeWalker, args: &GenerateArgs) -> T
 
             (
                 quote! {
-                    #[derive(Clone)]
+                    #[derive(Debug, Clone)]
                     pub enum OrderByParam {
                         #(#variants),*
                     }
@@ -136,7 +138,7 @@ pub fn enum_definition(comp_type: CompositeTypeWalker, args: &GenerateArgs) -> T
         .unwrap_or_default();
 
     quote! {
-        #[derive(Clone)]
+        #[derive(Debug, Clone)]
         pub enum OrderByParam {
             #(#variants),*
         }
@@ -2,14 +2,14 @@ use prisma_client_rust_sdk::{prelude::*, prisma::prisma_models::walkers::Composi
 
 use super::CompositeTypeModulePart;
 
-pub fn create_fn(comp_type: CompositeTypeWalker) -> Option<TokenStream> {
+pub fn create_fn(comp_type: CompositeTypeWalker, args: &GenerateArgs) -> Option<TokenStream> {
     comp_type
         .fields()
         .filter(|f| f.required_on_create())
         .map(|field| {
             Some((
                 snake_ident(field.name()),
-                field.type_tokens(&quote!(super::))?,
+                field.type_tokens(&quote!(super::), args.bigint_type)?,
             ))
         })
         .collect::<Option<Vec<_>>>()
@@ -22,7 +22,7 @@ pub fn create_fn(comp_type: CompositeTypeWalker) -> Option<TokenStream> {
                 .map(|name_snake| quote!(#name_snake::set(self.#name_snake)));
 
             quote! {
-                #[derive(Clone)]
+                #[derive(Debug, Clone)]
                 pub struct Create {
                     #(pub #required_field_names: #required_field_types,)*
                     pub _params: Vec<SetParam>
@@ -51,16 +51,16 @@ pub fn create_fn(comp_type: CompositeTypeWalker) -> Option<TokenStream> {
         })
 }
 
-pub fn module_part(comp_type: CompositeTypeWalker) -> CompositeTypeModulePart {
+pub fn module_part(comp_type: CompositeTypeWalker, args: &GenerateArgs) -> CompositeTypeModulePart {
     let ((variants, into_pv_arms), fields): ((Vec<_>, Vec<_>), _) = comp_type
         .fields()
         .flat_map(|field| {
             let field_name_snake = snake_ident(field.name());
             let field_name_pascal = pascal_ident(field.name());
-            let field_type = field.type_tokens(&quote!(super::))?;
+            let field_type = field.type_tokens(&quote!(super::), args.bigint_type)?;
 
             let variant_name = format_ident!("Set{field_name_pascal}");
-            let converter = field.type_prisma_value(&format_ident!("value"))?;
+            let converter = field.type_prisma_value(&format_ident!("value"), args.bigint_type)?;
 
             Some((
                 (
@@ -86,7 +86,7 @@ pub fn module_part(comp_type: CompositeTypeWalker) -> CompositeTypeModulePart {
 
     CompositeTypeModulePart {
         data: quote! {
-           #[derive(Clone)]
+           #[derive(Debug, Clone)]
            pub enum SetParam {
                #(#variants),*
            }
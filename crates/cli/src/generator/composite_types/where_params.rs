@@ -4,7 +4,7 @@ use crate::generator::prelude::*;
 
 use super::CompositeTypeModulePart;
 
-pub fn module_part(comp_type: CompositeTypeWalker) -> CompositeTypeModulePart {
+pub fn module_part(comp_type: CompositeTypeWalker, args: &GenerateArgs) -> CompositeTypeModulePart {
     let pcr = quote!(::prisma_client_rust);
 
     let ((variants, match_arms), fields): ((Vec<_>, Vec<_>), _) = comp_type
@@ -13,9 +13,9 @@ pub fn module_part(comp_type: CompositeTypeWalker) -> CompositeTypeModulePart {
             let field_name_snake = snake_ident(field.name());
             let field_name_pascal = pascal_ident(field.name());
 
-            let field_type = field.type_tokens(&quote!());
+            let field_type = field.type_tokens(&quote!(), args.bigint_type);
             let value_ident = format_ident!("value");
-            let value_to_pv = field.type_prisma_value(&value_ident);
+            let value_to_pv = field.type_prisma_value(&value_ident, args.bigint_type);
 
             let where_variant_name = format_ident!("{field_name_pascal}Equals");
 
@@ -43,7 +43,7 @@ pub fn module_part(comp_type: CompositeTypeWalker) -> CompositeTypeModulePart {
 
     CompositeTypeModulePart {
         data: quote! {
-            #[derive(Clone)]
+            #[derive(Debug, Clone)]
             pub enum WhereParam {
                 #(#variants),*
             }
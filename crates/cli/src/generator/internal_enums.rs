@@ -16,6 +16,20 @@ fn sort_order_impl() -> TokenStream {
         }
     }
 }
+
+/// Only generated for connectors that support ordering nulls
+fn nulls_order_impl() -> TokenStream {
+    let pcr = quote!(::prisma_client_rust);
+
+    quote! {
+        impl Into<#pcr::PrismaValue> for NullsOrder {
+            fn into(self) -> #pcr::PrismaValue {
+                #pcr::PrismaValue::Enum(self.to_string())
+            }
+        }
+    }
+}
+
 pub fn generate(args: &GenerateArgs) -> TokenStream {
     let internal_enums = args
         .dmmf
@@ -74,9 +88,21 @@ pub fn generate(args: &GenerateArgs) -> TokenStream {
 
     let sort_order_impl = sort_order_impl();
 
+    let nulls_order_impl = args
+        .dmmf
+        .schema
+        .enum_types
+        .get("prisma")
+        .unwrap()
+        .iter()
+        .any(|e| e.name == "NullsOrder")
+        .then(nulls_order_impl);
+
     quote! {
         #(#internal_enums)*
 
         #sort_order_impl
+
+        #nulls_order_impl
     }
 }
@@ -7,7 +7,7 @@ mod models;
 mod read_filters;
 mod write_params;
 
-use prisma_client_rust_sdk::prelude::*;
+use prisma_client_rust_sdk::{prelude::*, prisma::prisma_models::walkers::ScalarFieldWalker};
 use serde::Serialize;
 
 fn default_module_path() -> String {
@@ -18,12 +18,64 @@ fn default_module_path() -> String {
 pub struct PrismaClientRustGenerator {
     #[serde(default = "default_module_path")]
     module_path: String,
+    #[serde(default)]
+    bigint_type: BigIntType,
 }
 
 #[derive(Debug, Serialize, thiserror::Error)]
 pub enum Error {
     #[error("Failed to parse module_path")]
     InvalidModulePath,
+    #[error("{model}.{field} uses @default({function}({version})), which is not a version of {function} that IDs can be generated for")]
+    UnsupportedIdVersion {
+        model: String,
+        field: String,
+        function: String,
+        version: String,
+    },
+}
+
+/// The function of `prisma_client_rust::ids` that generates IDs
+/// in the same format as a field's `uuid` or `cuid` default.
+/// Returns the function and version of the default if no such function exists.
+pub(crate) fn id_generator(field: ScalarFieldWalker) -> Option<Result<&'static str, (&str, &str)>> {
+    let (function, arguments, _) = field.default_value()?.value().as_function()?;
+
+    let version = arguments.arguments.first().map(|argument| {
+        argument
+            .value
+            .as_numeric_value()
+            .map(|(version, _)| version)
+            .unwrap_or_default()
+    });
+
+    Some(match (function, version) {
+        ("uuid", None | Some("4")) => Ok("uuid"),
+        ("uuid", Some("7")) => Ok("uuid_v7"),
+        ("cuid", None | Some("1")) => Ok("cuid"),
+        ("cuid", Some("2")) => Ok("cuid2"),
+        ("uuid" | "cuid", Some(version)) => Err((function, version)),
+        _ => return None,
+    })
+}
+
+/// Versions of `uuid()` and `cuid()` without a matching `generate` function are rejected,
+/// rather than generating IDs in the wrong format
+fn check_id_defaults(args: &GenerateArgs) -> Result<(), Error> {
+    for model in args.schema.db.walk_models() {
+        for field in model.scalar_fields() {
+            if let Some(Err((function, version))) = id_generator(field) {
+                return Err(Error::UnsupportedIdVersion {
+                    model: model.name().to_string(),
+                    field: field.name().to_string(),
+                    function: function.to_string(),
+                    version: version.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
 }
 
 impl PrismaGenerator for PrismaClientRustGenerator {
@@ -33,6 +85,13 @@ impl PrismaGenerator for PrismaClientRustGenerator {
     type Error = Error;
 
     fn generate(self, args: GenerateArgs) -> Result<String, Self::Error> {
+        let args = GenerateArgs {
+            bigint_type: self.bigint_type,
+            ..args
+        };
+
+        check_id_defaults(&args)?;
+
         let header = header::generate(&args);
 
         let module_path = self
@@ -1,13 +1,16 @@
 use crate::generator::prelude::{prisma::psl::datamodel_connector, *};
 use prisma_client_rust_sdk::{
-    prisma::{prisma_models::walkers::ModelWalker, psl::parser_database::ScalarFieldType},
+    prisma::{
+        prisma_models::walkers::ModelWalker,
+        psl::parser_database::{ScalarFieldType, ScalarType},
+    },
     GenerateArgs,
 };
 
 use super::required_fields;
 
-pub fn create_fn(model: ModelWalker) -> Option<TokenStream> {
-    let (names, (types, push_wrapper)): (Vec<_>, (Vec<_>, Vec<_>)) = required_fields(model)?
+pub fn create_fn(model: ModelWalker, args: &GenerateArgs) -> Option<TokenStream> {
+    let (names, (types, push_wrapper)): (Vec<_>, (Vec<_>, Vec<_>)) = required_fields(model, args)?
         .into_iter()
         .map(|field| {
             (
@@ -31,8 +34,8 @@ pub fn create_fn(model: ModelWalker) -> Option<TokenStream> {
     })
 }
 
-pub fn create_unchecked_fn(model: ModelWalker) -> Option<TokenStream> {
-    required_fields(model)?;
+pub fn create_unchecked_fn(model: ModelWalker, args: &GenerateArgs) -> Option<TokenStream> {
+    required_fields(model, args)?;
 
     let (names, types): (Vec<_>, Vec<_>) = model
         .scalar_fields()
@@ -53,7 +56,7 @@ pub fn create_unchecked_fn(model: ModelWalker) -> Option<TokenStream> {
 
                         quote!(super::#comp_type_snake::Create)
                     }
-                    _ => field.type_tokens(&quote!(super::))?,
+                    _ => field.type_tokens(&quote!(super::), args.bigint_type)?,
                 },
             ))
         })
@@ -91,9 +94,9 @@ pub fn create_many_fn(model: ModelWalker) -> Option<TokenStream> {
         })
 }
 
-pub fn upsert_fn(model: ModelWalker) -> Option<TokenStream> {
+pub fn upsert_fn(model: ModelWalker, args: &GenerateArgs) -> Option<TokenStream> {
     // necessary to check whether CreateData is even available
-    let _ = required_fields(model)?;
+    let _ = required_fields(model, args)?;
 
     Some(quote! {
         pub fn upsert(
@@ -109,6 +112,43 @@ pub fn upsert_fn(model: ModelWalker) -> Option<TokenStream> {
                 _update
             )
         }
+
+        pub fn create_or_update(
+            self,
+            _where: UniqueWhereParam,
+            data: Create,
+            _overrides: Vec<SetParam>
+        ) -> UpsertQuery<'a> {
+            let _create = data.to_params();
+
+            let mut _update = _create.clone();
+            _update.extend(_overrides);
+
+            UpsertQuery::new(
+                self.client,
+                _where.into(),
+                _create,
+                _update
+            )
+        }
+
+        pub fn upsert_many(
+            self,
+            data: Vec<(UniqueWhereParam, Create, Vec<SetParam>)>
+        ) -> UpsertManyQuery<'a> {
+            UpsertManyQuery::new(
+                self.client,
+                data
+                    .into_iter()
+                    .map(|(_where, _create, _update)| UpsertQuery::new(
+                        self.client,
+                        _where.into(),
+                        _create.to_params(),
+                        _update
+                    ))
+                    .collect()
+            )
+        }
     })
 }
 
@@ -126,13 +166,126 @@ pub fn mongo_raw_fns() -> Option<TokenStream> {
     })
 }
 
+/// Models with a required `Int` or `BigInt` field named `version` get an optimistically locked update,
+/// which only applies if the record's version hasn't changed and increments it.
+fn update_if_version_fn(model: ModelWalker, args: &GenerateArgs) -> Option<TokenStream> {
+    let pcr = quote!(::prisma_client_rust);
+
+    let field = model
+        .scalar_fields()
+        .find(|field| field.name() == "version")?;
+
+    // non-unique fields can only be filtered on in an update's where with the extendedWhereUnique preview feature
+    args.dmmf
+        .schema
+        .find_input_type(&format!("{}WhereUniqueInput", model.name()))
+        .filter(|input_type| input_type.fields.iter().any(|f| f.name == field.name()))?;
+
+    let version_type = match field.scalar_field_type() {
+        ScalarFieldType::BuiltInScalar(typ @ (ScalarType::Int | ScalarType::BigInt))
+            if field.ast_field().arity.is_required() =>
+        {
+            typ.to_tokens(args.bigint_type)
+        }
+        _ => return None,
+    };
+
+    Some(quote! {
+        /// Updates the record only if its `version` field equals `expected_version`, incrementing the version,
+        /// and returns `QueryError::StaleVersion` if the record doesn't exist or its version has changed.
+        /// Generated for models with a required `Int` or `BigInt` field named `version`
+        /// when the `extendedWhereUnique` preview feature is enabled.
+        pub async fn update_if_version(
+            self,
+            _where: UniqueWhereParam,
+            expected_version: #version_type,
+            mut _params: Vec<SetParam>
+        ) -> #pcr::Result<Data> {
+            _params.push(version::increment(1));
+
+            UpdateQuery::new(self.client, _where.into(), _params, vec![])
+                .where_filter(version::equals(expected_version))
+                .exec()
+                .await
+                .map_err(|e| match e.is_prisma_error::<#pcr::prisma_errors::query_engine::RecordRequiredButNotFound>() {
+                    true => #pcr::QueryError::StaleVersion,
+                    false => e,
+                })
+        }
+    })
+}
+
+/// pgvector columns are `Unsupported("vector")` to Prisma, so nearest neighbour queries
+/// are built as raw queries that select the model's other scalar fields and the distance.
+/// `WhereParam`s can't be converted to SQL, so the queries can't be filtered.
+fn nearest_fns(model: ModelWalker, args: &GenerateArgs) -> Vec<TokenStream> {
+    let pcr = quote!(::prisma_client_rust);
+
+    if psl::builtin_connectors::POSTGRES.name() != args.connector.name() {
+        return vec![];
+    }
+
+    let columns = model
+        .scalar_fields()
+        .filter(|field| {
+            !matches!(
+                field.scalar_field_type(),
+                ScalarFieldType::Unsupported(_) | ScalarFieldType::CompositeType(_)
+            )
+        })
+        .map(|field| format!(r#""{}" AS "{}""#, field.database_name(), field.name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    // models in a non-default schema with multiSchema need their table to be qualified
+    let table = match model.schema_name() {
+        Some(schema) => format!(r#""{schema}"."{}""#, model.database_name()),
+        None => format!(r#""{}""#, model.database_name()),
+    };
+
+    model
+        .scalar_fields()
+        .filter(|field| {
+            matches!(
+                field.ast_field().field_type.as_unsupported(),
+                Some(("vector", _))
+            )
+        })
+        .map(|field| {
+            let fn_name = format_ident!("nearest_{}", snake_ident(field.name()));
+
+            // records without an embedding have no distance, so they're excluded
+            let sql = format!(
+                r#"SELECT {columns}, "{field}" <-> {{}}::vector AS "_distance" FROM {table} WHERE "{field}" IS NOT NULL ORDER BY "_distance" LIMIT {{}}"#,
+                field = field.database_name()
+            );
+
+            quote! {
+                pub fn #fn_name(self, embedding: &[f32], k: i64) -> #pcr::QueryRaw<'a, #pcr::Nearest<Data>> {
+                    #pcr::QueryRaw::new(
+                        self.client,
+                        #pcr::raw!(
+                            #sql,
+                            #pcr::pg_vector(embedding),
+                            #pcr::PrismaValue::Int(k)
+                        ),
+                        super::DATABASE_STR,
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
 pub fn struct_definition(model: ModelWalker, args: &GenerateArgs) -> TokenStream {
     let pcr = quote!(::prisma_client_rust);
 
-    let create_fn = create_fn(model);
-    let create_unchecked_fn = create_unchecked_fn(model);
-    let upsert_fn = upsert_fn(model);
+    let create_fn = create_fn(model, args);
+    let create_unchecked_fn = create_unchecked_fn(model, args);
+    let upsert_fn = upsert_fn(model, args);
     let monogo_raw_fns = mongo_raw_fns();
+    let nearest_fns = nearest_fns(model, args);
+    let update_if_version_fn = update_if_version_fn(model, args);
 
     let create_many_fn = (args
         .connector
@@ -199,6 +352,8 @@ pub fn struct_definition(model: ModelWalker, args: &GenerateArgs) -> TokenStream
                 )
             }
 
+            #update_if_version_fn
+
             #upsert_fn
 
             pub fn delete(self, _where: UniqueWhereParam) -> DeleteQuery<'a> {
@@ -223,7 +378,24 @@ pub fn struct_definition(model: ModelWalker, args: &GenerateArgs) -> TokenStream
                 )
             }
 
+            pub fn aggregate(self, _where: Vec<WhereParam>) -> AggregateQuery<'a> {
+                AggregateQuery::new(
+                    self.client,
+                    _where
+                )
+            }
+
+            pub fn group_by(self, by: Vec<ScalarField>, _where: Vec<WhereParam>) -> GroupByQuery<'a> {
+                GroupByQuery::new(
+                    self.client,
+                    by,
+                    _where
+                )
+            }
+
             #monogo_raw_fns
+
+            #(#nearest_fns)*
         }
     }
 }
@@ -0,0 +1,369 @@
+use prisma_client_rust_sdk::prisma::{
+    prisma_models::{walkers::ModelWalker, FieldArity},
+    psl::parser_database::{ScalarFieldType, ScalarType},
+};
+
+use crate::generator::prelude::*;
+
+use super::{where_params::OPERATORS, ModelModulePart};
+
+/// The type of an aggregated value, along with how to convert a `value` of it
+/// to a `PrismaValue` for use in `having` filters
+#[derive(Clone)]
+struct AggregateType {
+    typ: TokenStream,
+    prisma_value: TokenStream,
+}
+
+impl AggregateType {
+    fn scalar(typ: ScalarType, bigint_type: BigIntType) -> Self {
+        Self {
+            typ: typ.to_tokens(bigint_type),
+            prisma_value: typ.to_prisma_value(&format_ident!("value"), bigint_type),
+        }
+    }
+}
+
+struct AggregateField {
+    name: String,
+    avg: Option<AggregateType>,
+    sum: Option<AggregateType>,
+    min_max: Option<AggregateType>,
+}
+
+fn aggregate_fields(model: ModelWalker, args: &GenerateArgs) -> Vec<AggregateField> {
+    model
+        .scalar_fields()
+        .filter_map(|field| {
+            let scalar_type = match field.scalar_field_type() {
+                ScalarFieldType::CompositeType(_) | ScalarFieldType::Unsupported(_) => return None,
+                ScalarFieldType::BuiltInScalar(typ) => Some(typ),
+                ScalarFieldType::Enum(_) => None,
+            };
+
+            let is_list = field.ast_field().arity.is_list();
+
+            let (avg, sum) = match scalar_type.filter(|_| !is_list) {
+                // sums of Int fields can overflow i32
+                Some(ScalarType::Int) => (
+                    Some(AggregateType::scalar(ScalarType::Float, args.bigint_type)),
+                    Some(AggregateType {
+                        typ: quote!(i64),
+                        prisma_value: ScalarType::Int
+                            .to_prisma_value(&format_ident!("value"), args.bigint_type),
+                    }),
+                ),
+                Some(ScalarType::BigInt) => (
+                    Some(AggregateType::scalar(ScalarType::Float, args.bigint_type)),
+                    Some(AggregateType::scalar(ScalarType::BigInt, args.bigint_type)),
+                ),
+                Some(ScalarType::Float) => (
+                    Some(AggregateType::scalar(ScalarType::Float, args.bigint_type)),
+                    Some(AggregateType::scalar(ScalarType::Float, args.bigint_type)),
+                ),
+                Some(ScalarType::Decimal) => (
+                    Some(AggregateType::scalar(ScalarType::Decimal, args.bigint_type)),
+                    Some(AggregateType::scalar(ScalarType::Decimal, args.bigint_type)),
+                ),
+                _ => (None, None),
+            };
+
+            let min_max = match scalar_type {
+                _ if is_list => None,
+                Some(ScalarType::Json) => None,
+                _ => Some(AggregateType {
+                    typ: field.scalar_field_type().to_tokens(
+                        &quote!(super::),
+                        &FieldArity::Required,
+                        field.db,
+                        args.bigint_type,
+                    )?,
+                    prisma_value: field.scalar_field_type().to_prisma_value(
+                        &format_ident!("value"),
+                        &FieldArity::Required,
+                        args.bigint_type,
+                    )?,
+                }),
+            };
+
+            Some(AggregateField {
+                name: field.name().to_string(),
+                avg,
+                sum,
+                min_max,
+            })
+        })
+        .collect()
+}
+
+fn data_struct<'a>(
+    name: &str,
+    extra_fields: TokenStream,
+    fields: impl Iterator<Item = (&'a str, TokenStream)>,
+) -> TokenStream {
+    let name = format_ident!("{name}");
+
+    let fields = fields.map(|(field_name_str, typ)| {
+        let field_name_snake = snake_ident(field_name_str);
+
+        quote! {
+            #[serde(rename = #field_name_str, default)]
+            pub #field_name_snake: Option<#typ>
+        }
+    });
+
+    quote! {
+        #[derive(Debug, Clone, Default, ::serde::Serialize, ::serde::Deserialize)]
+        pub struct #name {
+            #extra_fields
+            #(#fields),*
+        }
+    }
+}
+
+pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
+    let pcr = quote!(::prisma_client_rust);
+
+    let fields = aggregate_fields(model, args);
+
+    let variants = fields.iter().map(|field| {
+        let field_name_pascal = pascal_ident(&field.name);
+
+        quote!(#field_name_pascal(#pcr::AggregateOperation))
+    });
+
+    let into_arms = fields.iter().map(|field| {
+        let field_name_str = &field.name;
+        let field_name_pascal = pascal_ident(&field.name);
+
+        quote!(Self::#field_name_pascal(op) => (op, #field_name_str.to_string()))
+    });
+
+    let count_data = data_struct(
+        "AggregateCountData",
+        quote! {
+            #[serde(rename = "_all", default)]
+            pub _all: Option<i64>,
+        },
+        fields.iter().map(|f| (f.name.as_str(), quote!(i64))),
+    );
+    let avg_data = data_struct(
+        "AggregateAvgData",
+        quote!(),
+        fields
+            .iter()
+            .filter_map(|f| Some((f.name.as_str(), f.avg.clone()?.typ))),
+    );
+    let sum_data = data_struct(
+        "AggregateSumData",
+        quote!(),
+        fields
+            .iter()
+            .filter_map(|f| Some((f.name.as_str(), f.sum.clone()?.typ))),
+    );
+    let min_data = data_struct(
+        "AggregateMinData",
+        quote!(),
+        fields
+            .iter()
+            .filter_map(|f| Some((f.name.as_str(), f.min_max.clone()?.typ))),
+    );
+    let max_data = data_struct(
+        "AggregateMaxData",
+        quote!(),
+        fields
+            .iter()
+            .filter_map(|f| Some((f.name.as_str(), f.min_max.clone()?.typ))),
+    );
+
+    let having_ops = |field: &AggregateField| {
+        let field_pascal = pascal_ident(&field.name);
+
+        [
+            (
+                "count",
+                Some(AggregateType {
+                    typ: quote!(i64),
+                    prisma_value: quote!(#pcr::PrismaValue::Int(value)),
+                }),
+            ),
+            ("avg", field.avg.clone()),
+            ("sum", field.sum.clone()),
+            ("min", field.min_max.clone()),
+            ("max", field.min_max.clone()),
+        ]
+        .into_iter()
+        .filter_map(move |(op, typ)| {
+            let variant = format_ident!("{field_pascal}{}", pascal_ident(op));
+            Some((op, variant, typ?))
+        })
+    };
+
+    let having_variants = fields.iter().flat_map(|field| {
+        let pcr = &pcr;
+
+        having_ops(field).map(move |(_, variant, AggregateType { typ, .. })| {
+            quote!(#variant(#pcr::AggregateFilter<#typ>))
+        })
+    });
+
+    let having_arms = fields.iter().flat_map(|field| {
+        let pcr = &pcr;
+        let field_name_str = &field.name;
+
+        having_ops(field).map(move |(op, variant, AggregateType { prisma_value, .. })| {
+            let op_str = format!("_{op}");
+
+            quote! {
+                Self::#variant(filter) => (
+                    #field_name_str,
+                    #pcr::SerializedWhereValue::Object(vec![(
+                        #op_str.to_string(),
+                        #pcr::PrismaValue::Object(vec![filter.serialize(|value| #prisma_value)])
+                    )])
+                )
+            }
+        })
+    });
+
+    let having_operator_arms = OPERATORS.iter().map(|op| {
+        let variant_name = format_ident!("{}", op.name);
+        let op_action = &op.action;
+
+        let value = match op.list {
+            true => quote! {
+                #pcr::SerializedWhereValue::List(
+                    value
+                        .into_iter()
+                        .map(#pcr::WhereInput::serialize)
+                        .map(|p| #pcr::PrismaValue::Object(vec![p.into()]))
+                        .collect()
+                )
+            },
+            false => quote! {
+                #pcr::SerializedWhereValue::Object(
+                    #pcr::merge_fields(
+                        value
+                            .into_iter()
+                            .map(#pcr::WhereInput::serialize)
+                            .map(Into::into)
+                            .collect()
+                    )
+                )
+            },
+        };
+
+        quote!(Self::#variant_name(value) => (#op_action, #value))
+    });
+
+    let field_stuff = fields
+        .iter()
+        .map(|field| {
+            let field_name_pascal = pascal_ident(&field.name);
+
+            let op_fns = [
+                (true, "count", quote!(Count)),
+                (field.avg.is_some(), "avg", quote!(Avg)),
+                (field.sum.is_some(), "sum", quote!(Sum)),
+                (field.min_max.is_some(), "min", quote!(Min)),
+                (field.min_max.is_some(), "max", quote!(Max)),
+            ]
+            .into_iter()
+            .filter(|(supported, _, _)| *supported)
+            .map(|(_, fn_name, op)| {
+                let fn_name = format_ident!("{fn_name}");
+
+                quote! {
+                    pub fn #fn_name() -> super::AggregateParam {
+                        super::AggregateParam::#field_name_pascal(#pcr::AggregateOperation::#op)
+                    }
+                }
+            });
+
+            let having_fns = having_ops(field).map(|(op, variant, AggregateType { typ, .. })| {
+                let fn_name = format_ident!("having_{op}");
+
+                quote! {
+                    pub fn #fn_name(filter: #pcr::AggregateFilter<#typ>) -> super::HavingParam {
+                        super::HavingParam::#variant(filter)
+                    }
+                }
+            });
+
+            (field.name.clone(), quote!(#(#op_fns)* #(#having_fns)*))
+        })
+        .collect();
+
+    ModelModulePart {
+        data: quote! {
+            #[derive(Debug, Clone)]
+            pub enum AggregateParam {
+                CountAll,
+                #(#variants),*
+            }
+
+            impl Into<(#pcr::AggregateOperation, String)> for AggregateParam {
+                fn into(self) -> (#pcr::AggregateOperation, String) {
+                    match self {
+                        Self::CountAll => (#pcr::AggregateOperation::Count, "_all".to_string()),
+                        #(#into_arms),*
+                    }
+                }
+            }
+
+            pub fn count_all() -> AggregateParam {
+                AggregateParam::CountAll
+            }
+
+            #[derive(Debug, Clone)]
+            pub enum HavingParam {
+                Not(Vec<HavingParam>),
+                Or(Vec<HavingParam>),
+                And(Vec<HavingParam>),
+                #(#having_variants),*
+            }
+
+            impl #pcr::WhereInput for HavingParam {
+                fn serialize(self) -> #pcr::SerializedWhereInput {
+                    let (name, value) = match self {
+                        #(#having_operator_arms,)*
+                        #(#having_arms),*
+                    };
+
+                    #pcr::SerializedWhereInput::new(name.to_string(), value.into())
+                }
+            }
+
+            impl From<#pcr::Operator<Self>> for HavingParam {
+                fn from(op: #pcr::Operator<Self>) -> Self {
+                    match op {
+                        #pcr::Operator::Not(value) => Self::Not(value),
+                        #pcr::Operator::And(value) => Self::And(value),
+                        #pcr::Operator::Or(value) => Self::Or(value),
+                    }
+                }
+            }
+
+            #count_data
+            #avg_data
+            #sum_data
+            #min_data
+            #max_data
+
+            #[derive(Debug, Clone, Default, ::serde::Serialize, ::serde::Deserialize)]
+            pub struct AggregateData {
+                #[serde(rename = "_count", default)]
+                pub _count: Option<AggregateCountData>,
+                #[serde(rename = "_avg", default)]
+                pub _avg: Option<AggregateAvgData>,
+                #[serde(rename = "_sum", default)]
+                pub _sum: Option<AggregateSumData>,
+                #[serde(rename = "_min", default)]
+                pub _min: Option<AggregateMinData>,
+                #[serde(rename = "_max", default)]
+                pub _max: Option<AggregateMaxData>,
+            }
+        },
+        fields: field_stuff,
+    }
+}
@@ -6,8 +6,8 @@ use crate::generator::prelude::*;
 
 use super::required_fields;
 
-fn create_unchecked(model: ModelWalker) -> Option<TokenStream> {
-    required_fields(model)?;
+fn create_unchecked(model: ModelWalker, args: &GenerateArgs) -> Option<TokenStream> {
+    required_fields(model, args)?;
 
     let model_name_snake = snake_ident(model.name());
 
@@ -30,14 +30,14 @@ fn create_unchecked(model: ModelWalker) -> Option<TokenStream> {
 
                         quote!(super::#comp_type_snake::Create)
                     }
-                    _ => field.type_tokens(&quote!(super::))?,
+                    _ => field.type_tokens(&quote!(super::), args.bigint_type)?,
                 },
             ))
         })
         .unzip();
 
     Some(quote! {
-        #[derive(Clone)]
+        #[derive(Debug, Clone)]
         pub struct CreateUnchecked {
             #(pub #names: #types,)*
             pub _params: Vec<UncheckedSetParam>
@@ -71,10 +71,10 @@ fn create_unchecked(model: ModelWalker) -> Option<TokenStream> {
     })
 }
 
-fn create(model: ModelWalker) -> Option<TokenStream> {
+fn create(model: ModelWalker, args: &GenerateArgs) -> Option<TokenStream> {
     let model_name_snake = snake_ident(model.name());
 
-    let (names, (types, push_wrappers)): (Vec<_>, (Vec<_>, Vec<_>)) = required_fields(model)?
+    let (names, (types, push_wrappers)): (Vec<_>, (Vec<_>, Vec<_>)) = required_fields(model, args)?
         .into_iter()
         .map(|field| {
             (
@@ -85,7 +85,7 @@ fn create(model: ModelWalker) -> Option<TokenStream> {
         .unzip();
 
     Some(quote! {
-       #[derive(Clone)]
+       #[derive(Debug, Clone)]
         pub struct Create {
             #(pub #names: #types,)*
             pub _params: Vec<SetParam>
@@ -119,9 +119,9 @@ fn create(model: ModelWalker) -> Option<TokenStream> {
     })
 }
 
-pub fn types(model: ModelWalker) -> TokenStream {
-    let create_unchecked = create_unchecked(model);
-    let create = create(model);
+pub fn types(model: ModelWalker, args: &GenerateArgs) -> TokenStream {
+    let create_unchecked = create_unchecked(model, args);
+    let create = create(model, args);
 
     quote! {
         #create
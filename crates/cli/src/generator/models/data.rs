@@ -8,9 +8,9 @@ use prisma_client_rust_sdk::prisma::{
 
 use crate::generator::prelude::*;
 
-use super::ModelModulePart;
+use super::{relation_count, ModelModulePart};
 
-pub fn model_data(model: ModelWalker) -> ModelModulePart {
+pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
     let fields = model
         .fields()
         .flat_map(|field| {
@@ -57,7 +57,7 @@ pub fn model_data(model: ModelWalker) -> ModelModulePart {
 
                             typ
                         }
-                        _ => (field.type_tokens(&quote!(super::))?, None),
+                        _ => (field.type_tokens(&quote!(super::), args.bigint_type)?, None),
                     }
                 }
             };
@@ -155,6 +155,13 @@ pub fn r#struct(model: ModelWalker) -> TokenStream {
         _ => None,
     });
 
+    let relation_count_field = (!relation_count::fields(model).is_empty()).then(|| {
+        quote! {
+            #[serde(rename = "_count", default, skip_serializing_if = "Option::is_none")]
+            pub _count: Option<CountData>,
+        }
+    });
+
     let specta_derive = cfg!(feature = "specta").then(|| {
         let model_name_pascal_str = pascal_ident(model.name()).to_string();
 
@@ -168,6 +175,7 @@ pub fn r#struct(model: ModelWalker) -> TokenStream {
         #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize)]
         #specta_derive
         pub struct Data {
+            #relation_count_field
             #(#struct_fields),*
         }
 
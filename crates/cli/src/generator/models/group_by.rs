@@ -0,0 +1,65 @@
+use prisma_client_rust_sdk::prisma::{
+    prisma_models::{walkers::ModelWalker, FieldArity},
+    psl::parser_database::ScalarFieldType,
+};
+
+use crate::generator::prelude::*;
+
+use super::ModelModulePart;
+
+pub fn model_data(model: ModelWalker) -> ModelModulePart {
+    let scalar_fields = model.scalar_fields().filter_map(|field| {
+        if matches!(
+            field.scalar_field_type(),
+            ScalarFieldType::CompositeType(_) | ScalarFieldType::Unsupported(_)
+        ) {
+            return None;
+        }
+
+        let field_name_str = field.name();
+        let field_name_snake = snake_ident(field_name_str);
+
+        let attrs = match field.ast_field().arity {
+            FieldArity::Optional => quote! {
+                #[serde(
+                    rename = #field_name_str,
+                    default,
+                    skip_serializing_if = "Option::is_none",
+                    with = "prisma_client_rust::serde::double_option"
+                )]
+            },
+            _ => quote! {
+                #[serde(rename = #field_name_str, default, skip_serializing_if = "Option::is_none")]
+            },
+        };
+
+        Some(quote! {
+            #attrs
+            pub #field_name_snake: Option<#field_name_snake::Type>
+        })
+    });
+
+    let scalar_field_enum = pascal_ident(&format!("{}ScalarFieldEnum", model.name()));
+
+    ModelModulePart {
+        data: quote! {
+            pub type ScalarField = #scalar_field_enum;
+
+            #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize)]
+            pub struct GroupByData {
+                #(#scalar_fields,)*
+                #[serde(rename = "_count", default)]
+                pub _count: Option<AggregateCountData>,
+                #[serde(rename = "_avg", default)]
+                pub _avg: Option<AggregateAvgData>,
+                #[serde(rename = "_sum", default)]
+                pub _sum: Option<AggregateSumData>,
+                #[serde(rename = "_min", default)]
+                pub _min: Option<AggregateMinData>,
+                #[serde(rename = "_max", default)]
+                pub _max: Option<AggregateMaxData>,
+            }
+        },
+        fields: Default::default(),
+    }
+}
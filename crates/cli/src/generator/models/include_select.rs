@@ -8,6 +8,8 @@ use prisma_client_rust_sdk::prisma::{
 
 use crate::generator::prelude::*;
 
+use super::relation_count;
+
 enum Variant {
     Select,
     Include,
@@ -36,6 +38,7 @@ impl Variant {
 
 fn model_macro<'a>(
     model: ModelWalker<'a>,
+    args: &GenerateArgs,
     module_path: &TokenStream,
     variant: Variant,
     // Fields that should always be included
@@ -69,7 +72,7 @@ fn model_macro<'a>(
 
     let field_type_impls = selection_fields.clone().map(|field| {
         let field_name_snake = snake_ident(field.name());
-        let field_type = field.type_tokens(module_path);
+        let field_type = field.type_tokens(module_path, args.bigint_type);
 
         let selection_type_impl = matches!(field.refine(), RefinedFieldWalker::Relation(_)).then(|| {
             let field_type = field
@@ -170,7 +173,7 @@ fn model_macro<'a>(
 
     let data_struct_scalar_fields = base_fields.clone().map(|f| {
         let field_name_snake = snake_ident(f.name());
-        let field_type = f.type_tokens(module_path);
+        let field_type = f.type_tokens(module_path, args.bigint_type);
 
         let specta_rename = cfg!(feature = "specta").then(|| {
             quote!(#[specta(rename_from_path = #module_path::#model_name_snake::#field_name_snake::NAME)])
@@ -182,11 +185,26 @@ fn model_macro<'a>(
         }
     });
 
-    let fields_enum_variants = selection_fields.clone().map(|f| {
-        let i = snake_ident(f.name());
-        quote!(#i)
+    let has_relation_counts = !relation_count::fields(model).is_empty();
+
+    let relation_count_selection_param_impl = has_relation_counts.then(|| {
+        quote! {
+            (@selection_field_to_selection_param; _count) => {
+                Into::<#model_module::#selection_param>::into(
+                    #model_module::_count::#variant_pascal
+                )
+            };
+        }
     });
 
+    let fields_enum_variants = selection_fields
+        .clone()
+        .map(|f| {
+            let i = snake_ident(f.name());
+            quote!(#i)
+        })
+        .chain(has_relation_counts.then(|| quote!(_count)));
+
     let field_serde_names = model
         .fields()
         .filter(|f| f.ast_field().field_type.as_unsupported().is_none())
@@ -431,6 +449,11 @@ fn model_macro<'a>(
                     }
                 }
             };
+            ($(($($func_arg:ident: $func_arg_ty:ty),+) =>)? $module_name:ident as $data_name:ident { $(#selection_pattern_produce)+ }) => {
+                #model_module::#variant_ident!($(($($func_arg: $func_arg_ty),+) =>)? $module_name { $(#selection_pattern_consume)+ });
+
+                pub type $data_name = $module_name::Data;
+            };
             ({ $(#selection_pattern_produce)+ }) => {{
                 #model_module::#variant_ident!(@definitions; ; $(#selection_pattern_consume)+);
 
@@ -484,6 +507,7 @@ fn model_macro<'a>(
             (@field_module; $($tokens:tt)*) => {};
 
             #(#selection_field_to_selection_param_impls)*
+            #relation_count_selection_param_impl
             (@selection_field_to_selection_param; $($tokens:tt)*) => { compile_error!(stringify!($($tokens)*)) }; // ::prisma_client_rust::Selection::builder("").build() };
 
             (@selections_to_params; : $macro_name:ident {$(#selection_pattern_produce)+}) => {
@@ -653,23 +677,63 @@ fn model_module_enum(model: ModelWalker, variant: Variant) -> TokenStream {
         .filter(|f| !f.ast_field().field_type.as_unsupported().is_some())
         .map(|field| pascal_ident(field.name()));
 
+    let (relation_count_variant, relation_count_arm) = (!relation_count::fields(model).is_empty())
+        .then(|| {
+            let count_pascal = pascal_ident("_count");
+
+            (
+                quote!(#count_pascal(_count::#variant_pascal)),
+                quote!(Self::#count_pascal(data) => data.to_selection()),
+            )
+        })
+        .unzip();
+
     let variant_param = variant.param();
 
     quote! {
         pub enum #variant_param {
-            #(#variants),*
+            #(#variants,)*
+            #relation_count_variant
         }
 
         impl #variant_param {
             pub fn to_selection(self) -> #pcr::Selection {
                 match self {
-                    #(Self::#field_names_pascal(data) => data.to_selection()),*
+                    #(Self::#field_names_pascal(data) => data.to_selection(),)*
+                    #relation_count_arm
                 }
             }
         }
     }
 }
 
+fn relation_count_module_enum(model: ModelWalker, variant: Variant) -> Option<TokenStream> {
+    let pcr = quote!(::prisma_client_rust);
+
+    let count_pascal = pascal_ident("_count");
+
+    let variant_pascal = pascal_ident(&variant.to_string());
+    let variant_param = variant.param();
+
+    (!relation_count::fields(model).is_empty()).then(|| {
+        quote! {
+            pub struct #variant_pascal;
+
+            impl Into<super::#variant_param> for #variant_pascal {
+                fn into(self) -> super::#variant_param {
+                    super::#variant_param::#count_pascal(self)
+                }
+            }
+
+            impl #variant_pascal {
+                pub fn to_selection(self) -> #pcr::Selection {
+                    super::CountParam::to_count_selection(super::CountParam::all())
+                }
+            }
+        }
+    })
+}
+
 pub mod include {
     use prisma_client_rust_sdk::prisma::prisma_models::walkers::{ModelWalker, RefinedFieldWalker};
 
@@ -677,9 +741,14 @@ pub mod include {
 
     use super::*;
 
-    pub fn model_data(model: ModelWalker, module_path: &TokenStream) -> ModelModulePart {
+    pub fn model_data(
+        model: ModelWalker,
+        args: &GenerateArgs,
+        module_path: &TokenStream,
+    ) -> ModelModulePart {
         let r#macro = super::model_macro(
             model,
+            args,
             module_path,
             Variant::Include,
             model
@@ -706,6 +775,10 @@ pub mod include {
                     super::field_module_enum(field, Variant::Include)
                         .map(|e| (field.name().to_string(), e))
                 })
+                .chain(
+                    super::relation_count_module_enum(model, Variant::Include)
+                        .map(|e| ("_count".to_string(), e)),
+                )
                 .collect(),
         }
     }
@@ -718,9 +791,14 @@ pub mod select {
 
     use super::*;
 
-    pub fn model_data(model: ModelWalker, module_path: &TokenStream) -> ModelModulePart {
+    pub fn model_data(
+        model: ModelWalker,
+        args: &GenerateArgs,
+        module_path: &TokenStream,
+    ) -> ModelModulePart {
         let r#macro = super::model_macro(
             model,
+            args,
             module_path,
             Variant::Select,
             vec![].into_iter(),
@@ -743,6 +821,10 @@ pub mod select {
                     super::field_module_enum(field, Variant::Select)
                         .map(|e| (field.name().to_string(), e))
                 })
+                .chain(
+                    super::relation_count_module_enum(model, Variant::Select)
+                        .map(|e| ("_count".to_string(), e)),
+                )
                 .collect(),
         }
     }
@@ -1,10 +1,13 @@
 mod actions;
+mod aggregate;
 mod create;
 mod data;
+mod group_by;
 mod include_select;
 mod order_by;
 mod pagination;
 mod partial_unchecked;
+mod relation_count;
 mod set_params;
 mod types;
 mod where_params;
@@ -27,7 +30,10 @@ pub struct RequiredField<'a> {
     pub inner: FieldWalker<'a>,
 }
 
-pub fn required_fields<'a>(model: ModelWalker<'a>) -> Option<Vec<RequiredField<'a>>> {
+pub fn required_fields<'a>(
+    model: ModelWalker<'a>,
+    args: &GenerateArgs,
+) -> Option<Vec<RequiredField<'a>>> {
     model
         .fields()
         .filter(|field| match field.refine() {
@@ -49,7 +55,7 @@ pub fn required_fields<'a>(model: ModelWalker<'a>) -> Option<Vec<RequiredField<'
 
                                 quote!(super::#type_snake::Create)
                             }
-                            _ => field.type_tokens(&quote!(super::))?,
+                            _ => field.type_tokens(&quote!(super::), args.bigint_type)?,
                         }
                     }
                     RefinedFieldWalker::Relation(relation_field) => {
@@ -88,19 +94,22 @@ pub fn modules(args: &GenerateArgs, module_path: &TokenStream) -> Vec<TokenStrea
             let actions_struct = actions::struct_definition(model, args);
 
             let field_module_stuff = ModelModulePart::combine(vec![
-                data::model_data(model),
+                data::model_data(model, args),
                 where_params::model_data(model, args, module_path),
                 order_by::model_data(model, args),
                 with_params::model_data(model),
                 set_params::model_data(model, args),
-                select::model_data(model, &module_path),
-                include::model_data(model, &module_path),
+                select::model_data(model, args, &module_path),
+                include::model_data(model, args, &module_path),
+                aggregate::model_data(model, args),
+                group_by::model_data(model),
+                relation_count::model_data(model),
             ]);
 
-            let create_types = create::types(model);
-            let types_struct = types::r#struct(model, module_path);
+            let create_types = create::types(model, args);
+            let types_struct = types::r#struct(model, args, module_path);
             let data_struct = data::r#struct(model);
-            let partial_unchecked_macro = partial_unchecked::r#macro(model, &module_path);
+            let partial_unchecked_macro = partial_unchecked::r#macro(model, args, &module_path);
 
             let mongo_raw_types = cfg!(feature = "mongodb").then(|| quote! {
 	            pub type FindRawQuery<'a, T: #pcr::Data> = #pcr::FindRaw<'a, Types, T>;
@@ -122,6 +131,7 @@ pub fn modules(args: &GenerateArgs, module_path: &TokenStream) -> Vec<TokenStrea
                     pub type UniqueArgs = #pcr::UniqueArgs<Types>;
                     pub type ManyArgs = #pcr::ManyArgs<Types>;
 
+                    pub type AggregateQuery<'a> = #pcr::Aggregate<'a, Types>;
                     pub type CountQuery<'a> = #pcr::Count<'a, Types>;
                     pub type CreateQuery<'a> = #pcr::Create<'a, Types>;
                     pub type CreateUncheckedQuery<'a> = #pcr::CreateUnchecked<'a, Types>;
@@ -129,10 +139,12 @@ pub fn modules(args: &GenerateArgs, module_path: &TokenStream) -> Vec<TokenStrea
                     pub type FindUniqueQuery<'a> = #pcr::FindUnique<'a, Types>;
                     pub type FindManyQuery<'a> = #pcr::FindMany<'a, Types>;
                     pub type FindFirstQuery<'a> = #pcr::FindFirst<'a, Types>;
+                    pub type GroupByQuery<'a> = #pcr::GroupBy<'a, Types>;
                     pub type UpdateQuery<'a> = #pcr::Update<'a, Types>;
                     pub type UpdateUncheckedQuery<'a> = #pcr::UpdateUnchecked<'a, Types>;
                     pub type UpdateManyQuery<'a> = #pcr::UpdateMany<'a, Types>;
                     pub type UpsertQuery<'a> = #pcr::Upsert<'a, Types>;
+                    pub type UpsertManyQuery<'a> = #pcr::UpsertMany<'a, Types>;
                     pub type DeleteQuery<'a> = #pcr::Delete<'a, Types>;
                     pub type DeleteManyQuery<'a> = #pcr::DeleteMany<'a, Types>;
 
@@ -7,7 +7,7 @@ use prisma_client_rust_sdk::prisma::{
 
 use crate::generator::prelude::*;
 
-use super::ModelModulePart;
+use super::{relation_count, ModelModulePart};
 
 pub fn fetch_builder_fn(model_name_snake: &Ident) -> TokenStream {
     quote! {
@@ -18,123 +18,224 @@ pub fn fetch_builder_fn(model_name_snake: &Ident) -> TokenStream {
     }
 }
 
-pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
+fn order_by_param<'a>(
+    model: ModelWalker,
+    args: &'a GenerateArgs,
+    input_type_suffix: &str,
+    param_name: &Ident,
+) -> (
+    TokenStream,
+    Vec<(&'a String, (TokenStream, TokenStream))>,
+    Vec<(&'a String, TokenStream)>,
+) {
     let pcr = quote!(::prisma_client_rust);
 
-    let (order_by_relation_aggregate_param, aggregate_field_stuff) = args
+    args
         .dmmf
         .schema
-        .find_input_type(&format!("{}OrderByRelationAggregateInput", model.name()))
+        .find_input_type(&format!("{}{input_type_suffix}", model.name()))
         .map(|input_type| {
-            let ((variants, into_pv_arms), field_things): ((Vec<_>, Vec<_>), Vec<_>) = input_type
+            let (((variants, into_pv_arms), field_stuff), nulls_field_stuff): (
+                ((Vec<_>, Vec<_>), Vec<_>),
+                Vec<_>,
+            ) = input_type
                 .fields
                 .iter()
                 .flat_map(|field| {
                     let field_name_str = &field.name;
                     let field_name_pascal = pascal_ident(&field.name);
 
-                    let typ = &field.input_types[0];
-                    let typ =
-                        typ.to_tokens(&quote!(super::), &FieldArity::Required, &args.schema.db)?;
+                    // only present for nullable fields when the connector supports it
+                    let nulls = field
+                        .input_types
+                        .iter()
+                        .any(|typ| typ.typ == "SortOrderInput")
+                        .then(|| {
+                            let variant = format_ident!("{field_name_pascal}WithNulls");
+
+                            (
+                                quote!(#variant(super::SortOrder, super::NullsOrder),),
+                                quote! {
+                                    Self::#variant(sort, nulls) => (
+                                        #field_name_str,
+                                        #pcr::PrismaValue::Object(vec![
+                                            ("sort".to_string(), sort.into()),
+                                            ("nulls".to_string(), nulls.into()),
+                                        ])
+                                    ),
+                                },
+                                (
+                                    field_name_str,
+                                    quote! {
+                                        impl From<OrderWithNulls> for super::#param_name {
+                                            fn from(OrderWithNulls(sort, nulls): OrderWithNulls) -> Self {
+                                                Self::#variant(sort, nulls)
+                                            }
+                                        }
+                                    },
+                                ),
+                            )
+                        });
+                    let (nulls_variant, nulls_arm, nulls_field_stuff) = match nulls {
+                        Some((variant, arm, field_stuff)) => (Some(variant), Some(arm), Some(field_stuff)),
+                        None => (None, None, None),
+                    };
+
+                    let typ_ref = &field.input_types[0];
+                    let typ = typ_ref.to_tokens(
+                        &quote!(super::),
+                        &FieldArity::Required,
+                        &args.schema.db,
+                        args.bigint_type,
+                    )?;
+
+                    let pv = match &typ_ref.location {
+                        TypeLocation::EnumTypes | TypeLocation::Scalar => quote!(param.into()),
+                        TypeLocation::InputObjectTypes => quote! {
+                            #pcr::PrismaValue::Object(
+                                param.into_iter().map(Into::into).collect()
+                            )
+                        },
+                        _ => return None,
+                    };
 
                     Some((
                         (
-                            quote!(#field_name_pascal(#typ)),
-                            quote! {
-                                Self::#field_name_pascal(param) => (
-                                    #field_name_str,
-                                    param.into()
-                                )
-                            },
-                        ),
-                        (
-                            field_name_str,
                             (
-                                typ,
+                                quote!(#field_name_pascal(#typ), #nulls_variant),
                                 quote! {
-                                    impl From<Order> for super::OrderByRelationAggregateParam {
-                                        fn from(Order(v): Order) -> Self {
-                                            Self::#field_name_pascal(v)
-                                        }
-                                    }
+                                    Self::#field_name_pascal(param) => (
+                                        #field_name_str,
+                                        #pv
+                                    ),
+                                    #nulls_arm
                                 },
                             ),
+                            (
+                                field_name_str,
+                                (
+                                    typ_ref.to_tokens(
+                                        &quote!(),
+                                        &FieldArity::Required,
+                                        &args.schema.db,
+                                        args.bigint_type,
+                                    )?,
+                                    quote! {
+                                        impl From<Order> for super::#param_name {
+                                            fn from(Order(v): Order) -> Self {
+                                                Self::#field_name_pascal(v)
+                                            }
+                                        }
+                                    },
+                                ),
+                            ),
                         ),
+                        nulls_field_stuff,
                     ))
                 })
                 .unzip();
 
+            // only present when the fullTextSearch preview feature is enabled
+            let (relevance_variant, relevance_arm) = input_type
+                .fields
+                .iter()
+                .find(|field| field.name == "_relevance")
+                .map(|field| {
+                    let field_name_str = &field.name;
+                    let field_name_pascal = pascal_ident(&field.name);
+
+                    let relevance_field_enum =
+                        pascal_ident(&format!("{}OrderByRelevanceFieldEnum", model.name()));
+
+                    (
+                        quote!(#field_name_pascal(Vec<super::#relevance_field_enum>, String, super::SortOrder),),
+                        quote! {
+                            Self::#field_name_pascal(fields, search, sort) => (
+                                #field_name_str,
+                                #pcr::PrismaValue::Object(vec![
+                                    (
+                                        "fields".to_string(),
+                                        #pcr::PrismaValue::List(
+                                            fields
+                                                .into_iter()
+                                                .map(|field| #pcr::PrismaValue::Enum(field.to_string()))
+                                                .collect()
+                                        )
+                                    ),
+                                    ("search".to_string(), #pcr::PrismaValue::String(search)),
+                                    ("sort".to_string(), sort.into()),
+                                ])
+                            ),
+                        },
+                    )
+                })
+                .unzip();
+
             (
                 quote! {
-                    #[derive(Clone)]
-                    pub enum OrderByRelationAggregateParam {
-                        #(#variants),*
+                    #[derive(Debug, Clone)]
+                    pub enum #param_name {
+                       #(#variants)*
+                       #relevance_variant
                     }
 
-                    impl Into<(String, #pcr::PrismaValue)> for OrderByRelationAggregateParam {
+                    impl Into<(String, #pcr::PrismaValue)> for #param_name {
                         fn into(self) -> (String, #pcr::PrismaValue) {
                             let (k, v) = match self {
-                                #(#into_pv_arms),*
+                                #(#into_pv_arms)*
+                                #relevance_arm
                             };
 
                             (k.to_string(), v)
                         }
                     }
                 },
-                field_things,
+                field_stuff,
+                nulls_field_stuff.into_iter().flatten().collect(),
             )
         })
-        .unwrap_or_default();
+        .unwrap_or_default()
+}
+
+pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
+    let pcr = quote!(::prisma_client_rust);
 
-    let (order_by_with_relation_param, relation_field_stuff) = args
+    let (order_by_relation_aggregate_param, aggregate_field_stuff) = args
         .dmmf
         .schema
-        .find_input_type(&format!("{}OrderByWithRelationInput", model.name()))
+        .find_input_type(&format!("{}OrderByRelationAggregateInput", model.name()))
         .map(|input_type| {
-            let ((variants, into_pv_arms), field_stuff): ((Vec<_>, Vec<_>), Vec<_>) = input_type
+            let ((variants, into_pv_arms), field_things): ((Vec<_>, Vec<_>), Vec<_>) = input_type
                 .fields
                 .iter()
                 .flat_map(|field| {
                     let field_name_str = &field.name;
                     let field_name_pascal = pascal_ident(&field.name);
 
-                    let typ_ref = &field.input_types[0];
-                    let typ = typ_ref.to_tokens(
+                    let typ = &field.input_types[0];
+                    let typ = typ.to_tokens(
                         &quote!(super::),
                         &FieldArity::Required,
                         &args.schema.db,
+                        args.bigint_type,
                     )?;
 
-                    let pv = match &typ_ref.location {
-                        TypeLocation::EnumTypes | TypeLocation::Scalar => quote!(param.into()),
-                        TypeLocation::InputObjectTypes => quote! {
-                            #pcr::PrismaValue::Object(
-                                param.into_iter().map(Into::into).collect()
-                            )
-                        },
-                        _ => return None,
-                    };
-
                     Some((
                         (
                             quote!(#field_name_pascal(#typ)),
                             quote! {
                                 Self::#field_name_pascal(param) => (
                                     #field_name_str,
-                                    #pv
+                                    param.into()
                                 )
                             },
                         ),
                         (
                             field_name_str,
                             (
-                                typ_ref.to_tokens(
-                                    &quote!(),
-                                    &FieldArity::Required,
-                                    &args.schema.db,
-                                )?,
+                                typ,
                                 quote! {
-                                    impl From<Order> for super::OrderByWithRelationParam {
+                                    impl From<Order> for super::OrderByRelationAggregateParam {
                                         fn from(Order(v): Order) -> Self {
                                             Self::#field_name_pascal(v)
                                         }
@@ -148,12 +249,12 @@ pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
 
             (
                 quote! {
-                    #[derive(Clone)]
-                    pub enum OrderByWithRelationParam {
-                       #(#variants),*
+                    #[derive(Debug, Clone)]
+                    pub enum OrderByRelationAggregateParam {
+                        #(#variants),*
                     }
 
-                    impl Into<(String, #pcr::PrismaValue)> for OrderByWithRelationParam {
+                    impl Into<(String, #pcr::PrismaValue)> for OrderByRelationAggregateParam {
                         fn into(self) -> (String, #pcr::PrismaValue) {
                             let (k, v) = match self {
                                 #(#into_pv_arms),*
@@ -163,19 +264,92 @@ pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
                         }
                     }
                 },
-                field_stuff,
+                field_things,
             )
         })
         .unwrap_or_default();
 
+    let (order_by_with_relation_param, relation_field_stuff, relation_nulls_field_stuff) =
+        order_by_param(
+            model,
+            args,
+            "OrderByWithRelationInput",
+            &format_ident!("OrderByWithRelationParam"),
+        );
+
+    let (order_by_with_aggregation_param, aggregation_field_stuff, aggregation_nulls_field_stuff) =
+        order_by_param(
+            model,
+            args,
+            "OrderByWithAggregationInput",
+            &format_ident!("OrderByWithAggregationParam"),
+        );
+
+    // shorthand for ordering by the number of related records
+    let order_by_count_fns = relation_count::fields(model)
+        .into_iter()
+        .map(|field| {
+            let relation_model_name_snake = snake_ident(field.related_model().name());
+
+            (
+                field.name().to_string(),
+                quote! {
+                    pub fn order_by_count<T: From<Order>>(sort: SortOrder) -> T {
+                        order(vec![#relation_model_name_snake::_count::order(sort)])
+                    }
+                },
+            )
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let nulls_field_stuff = relation_nulls_field_stuff
+        .into_iter()
+        .chain(aggregation_nulls_field_stuff)
+        .fold(BTreeMap::new(), |mut acc, (name, data)| {
+            let entry = acc.entry(name.to_string()).or_insert_with(|| vec![]);
+            entry.push(data);
+            acc
+        });
+
+    let order_by_relevance_fn = args
+        .dmmf
+        .schema
+        .find_input_type(&format!("{}OrderByWithRelationInput", model.name()))
+        .and_then(|input_type| {
+            input_type
+                .fields
+                .iter()
+                .find(|field| field.name == "_relevance")
+        })
+        .map(|field| {
+            let variant = pascal_ident(&field.name);
+            let relevance_field_enum =
+                pascal_ident(&format!("{}OrderByRelevanceFieldEnum", model.name()));
+
+            quote! {
+                pub type OrderByRelevanceField = #relevance_field_enum;
+
+                pub fn order_by_relevance(
+                    fields: Vec<OrderByRelevanceField>,
+                    search: impl Into<String>,
+                    sort: SortOrder
+                ) -> OrderByWithRelationParam {
+                    OrderByWithRelationParam::#variant(fields, search.into(), sort)
+                }
+            }
+        });
+
     ModelModulePart {
         data: quote! {
+            #order_by_relevance_fn
             #order_by_with_relation_param
+            #order_by_with_aggregation_param
             #order_by_relation_aggregate_param
         },
         fields: aggregate_field_stuff
             .into_iter()
             .chain(relation_field_stuff)
+            .chain(aggregation_field_stuff)
             .fold(BTreeMap::new(), |mut acc, (name, data)| {
                 let entry = acc.entry(name.to_string()).or_insert_with(|| vec![]);
                 entry.push(data);
@@ -190,6 +364,20 @@ pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
 
                 let impls = data.iter().map(|(_, impls)| impls);
 
+                let order_by_count_fn = order_by_count_fns.get(&name);
+
+                let nulls = nulls_field_stuff.get(&name).map(|nulls_impls| {
+                    quote! {
+                        pub struct OrderWithNulls(SortOrder, NullsOrder);
+
+                        pub fn order_with_nulls<T: From<OrderWithNulls>>(sort: SortOrder, nulls: NullsOrder) -> T {
+                            OrderWithNulls(sort, nulls).into()
+                        }
+
+                        #(#nulls_impls)*
+                    }
+                });
+
                 (
                     name,
                     quote! {
@@ -200,6 +388,10 @@ pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
                         }
 
                         #(#impls)*
+
+                        #nulls
+
+                        #order_by_count_fn
                     },
                 )
             })
@@ -12,6 +12,11 @@ pub fn fetch_builder_fns(model_name_snake: &Ident) -> TokenStream {
             self
         }
 
+        pub fn last(mut self, value: i64) -> Self {
+            self.0 = self.0.last(value);
+            self
+        }
+
         pub fn cursor(mut self, value: #model_name_snake::UniqueWhereParam) -> Self {
             self.0 = self.0.cursor(value.into());
             self
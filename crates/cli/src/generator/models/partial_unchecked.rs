@@ -2,7 +2,7 @@ use prisma_client_rust_sdk::prisma::prisma_models::walkers::ModelWalker;
 
 use crate::generator::prelude::*;
 
-pub fn r#macro(model: ModelWalker, module_path: &TokenStream) -> TokenStream {
+pub fn r#macro(model: ModelWalker, args: &GenerateArgs, module_path: &TokenStream) -> TokenStream {
     let model_name_snake = snake_ident(model.name());
     let model_name_snake_raw = snake_ident_raw(model.name());
     let macro_name = format_ident!("_partial_unchecked_{model_name_snake_raw}");
@@ -15,9 +15,12 @@ pub fn r#macro(model: ModelWalker, module_path: &TokenStream) -> TokenStream {
 
         let arity = scalar_field.ast_field().arity;
 
-        let field_type = scalar_field
-            .scalar_field_type()
-            .to_tokens(module_path, &arity, &model.db);
+        let field_type = scalar_field.scalar_field_type().to_tokens(
+            module_path,
+            &arity,
+            &model.db,
+            args.bigint_type,
+        );
 
         let double_option_attrs = arity.is_optional().then(|| {
             quote! {
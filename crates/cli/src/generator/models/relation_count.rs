@@ -0,0 +1,154 @@
+use prisma_client_rust_sdk::prisma::prisma_models::walkers::{ModelWalker, RelationFieldWalker};
+
+use crate::generator::prelude::*;
+
+use super::ModelModulePart;
+
+/// Relation fields that can be counted via `_count`
+pub fn fields(model: ModelWalker) -> Vec<RelationFieldWalker> {
+    model
+        .relation_fields()
+        .filter(|field| field.ast_field().arity.is_list())
+        .collect()
+}
+
+pub fn model_data(model: ModelWalker) -> ModelModulePart {
+    let pcr = quote!(::prisma_client_rust);
+
+    let fields = fields(model);
+
+    if fields.is_empty() {
+        return ModelModulePart {
+            data: quote!(),
+            fields: Default::default(),
+        };
+    }
+
+    let variants = fields
+        .iter()
+        .map(|field| pascal_ident(field.name()))
+        .collect::<Vec<_>>();
+    let field_names_snake = fields
+        .iter()
+        .map(|field| snake_ident(field.name()))
+        .collect::<Vec<_>>();
+    let relation_models_snake = fields
+        .iter()
+        .map(|field| snake_ident(field.related_model().name()))
+        .collect::<Vec<_>>();
+
+    let data_fields = fields.iter().map(|field| {
+        let field_name_str = field.name();
+        let field_name_snake = snake_ident(field_name_str);
+
+        quote! {
+            #[serde(rename = #field_name_str, default, skip_serializing_if = "Option::is_none")]
+            pub #field_name_snake: Option<i64>
+        }
+    });
+
+    let specta_derive = cfg!(feature = "specta").then(|| {
+        let count_name_pascal_str = format!("{}Count", pascal_ident(model.name()));
+
+        quote! {
+            #[derive(::prisma_client_rust::specta::Type)]
+            #[specta(rename = #count_name_pascal_str, crate = "prisma_client_rust::specta")]
+        }
+    });
+
+    ModelModulePart {
+        data: quote! {
+            #[derive(Debug, Clone)]
+            pub enum CountParam {
+                #(#variants(Vec<super::#relation_models_snake::WhereParam>)),*
+            }
+
+            impl CountParam {
+                pub fn all() -> Vec<Self> {
+                    vec![#(Self::#variants(vec![])),*]
+                }
+
+                fn name(&self) -> &'static str {
+                    match self {
+                        #(Self::#variants(_) => #field_names_snake::NAME),*
+                    }
+                }
+
+                pub fn to_selection(self) -> #pcr::Selection {
+                    let name = self.name();
+
+                    let where_params = match self {
+                        #(Self::#variants(params) => params.into_iter().map(#pcr::WhereInput::serialize).collect::<Vec<_>>()),*
+                    };
+
+                    #pcr::Selection::new(
+                        name,
+                        None,
+                        (!where_params.is_empty())
+                            .then(|| (
+                                "where".to_string(),
+                                #pcr::PrismaValue::Object(#pcr::merge_fields(
+                                    where_params
+                                        .into_iter()
+                                        .map(|s| (s.field, s.value.into()))
+                                        .collect()
+                                ))
+                                .into()
+                            ))
+                            .into_iter()
+                            .collect::<Vec<_>>(),
+                        []
+                    )
+                }
+
+                /// Only the last param for each relation is used
+                pub fn to_count_selection(params: impl IntoIterator<Item = Self>) -> #pcr::Selection {
+                    let params = params
+                        .into_iter()
+                        .map(|param| (param.name(), param))
+                        .collect::<::std::collections::BTreeMap<_, _>>();
+
+                    #pcr::Selection::new(
+                        "_count",
+                        None,
+                        [],
+                        params.into_values().map(Self::to_selection).collect::<Vec<_>>()
+                    )
+                }
+            }
+
+            #[derive(Debug, Clone, Default, ::serde::Serialize, ::serde::Deserialize)]
+            #specta_derive
+            pub struct CountData {
+                #(#data_fields),*
+            }
+        },
+        fields: fields
+            .iter()
+            .zip(&variants)
+            .zip(&relation_models_snake)
+            .map(|((field, variant), relation_model_snake)| {
+                (
+                    field.name().to_string(),
+                    quote! {
+                        pub fn count() -> super::CountParam {
+                            super::CountParam::#variant(vec![])
+                        }
+
+                        /// Only counts related records that match `where_params`.
+                        /// Requires the `filteredRelationCount` preview feature.
+                        pub fn count_where(where_params: Vec<#relation_model_snake::WhereParam>) -> super::CountParam {
+                            super::CountParam::#variant(where_params)
+                        }
+                    },
+                )
+            })
+            .chain([(
+                "_count".to_string(),
+                quote! {
+                    pub type Type = super::CountData;
+                },
+            )])
+            .collect(),
+    }
+}
@@ -5,7 +5,7 @@ use prisma_client_rust_sdk::prisma::{
         walkers::{FieldWalker, ModelWalker, RefinedFieldWalker, RelationFieldWalker},
         FieldArity,
     },
-    psl::parser_database::ScalarFieldType,
+    psl::parser_database::{ScalarFieldType, ScalarType},
 };
 
 use crate::generator::{prelude::*, write_params};
@@ -55,13 +55,42 @@ fn relation_field_set_params(field: RelationFieldWalker) -> Vec<RelationSetParam
     }
 }
 
+/// Nested `createMany` is only available for one-to-many relations on connectors that support it,
+/// so the relation's nested create input is checked for it.
+fn supports_nested_create_many(field: RelationFieldWalker, args: &GenerateArgs) -> bool {
+    args.dmmf
+        .schema
+        .find_input_type(&format!("{}CreateInput", field.model().name()))
+        .and_then(|input_type| input_type.fields.iter().find(|f| f.name == field.name()))
+        .into_iter()
+        .flat_map(|field| &field.input_types)
+        .filter_map(|typ| args.dmmf.schema.find_input_type(&typ.typ))
+        .any(|typ| typ.fields.iter().any(|f| f.name == "createMany"))
+}
+
+/// Json fields don't have update operations, so they're written directly.
+/// Prisma requires nulls in them to be explicit, so `None` is written as a database `NULL`.
+fn json_set_value(arity: &FieldArity, var: &Ident) -> TokenStream {
+    let pcr = quote!(::prisma_client_rust);
+
+    // Json values can't contain BigInts, so the BigInt type doesn't matter
+    let value = ScalarType::Json.to_prisma_value(var, BigIntType::default());
+
+    match arity {
+        FieldArity::Optional => quote! {
+            #var.map(|#var| #value).unwrap_or_else(|| #pcr::PrismaValue::Enum("DbNull".to_string()))
+        },
+        _ => value,
+    }
+}
+
 fn field_set_params(
     field: FieldWalker,
     args: &GenerateArgs,
 ) -> Option<(Vec<TokenStream>, Vec<TokenStream>, (String, TokenStream))> {
     let field_name_pascal = pascal_ident(field.name());
     let field_name_snake = snake_ident(field.name());
-    let field_type = field.type_tokens(&quote!());
+    let field_type = field.type_tokens(&quote!(), args.bigint_type);
 
     let pcr = quote!(::prisma_client_rust);
 
@@ -82,7 +111,7 @@ fn field_set_params(
                     .fields()
                     .filter(|f| f.required_on_create())
                     .map(|field| {
-                        field.type_tokens(&quote!())?;
+                        field.type_tokens(&quote!(), args.bigint_type)?;
                         Some(field)
                     })
                     .collect::<Option<Vec<_>>>()
@@ -375,6 +404,52 @@ fn field_set_params(
 
                 (field.name().to_string(), field_fns)
             }
+            ScalarFieldType::BuiltInScalar(ScalarType::Json) if !arity.is_list() => {
+                let value_ident = format_ident!("value");
+                let value = json_set_value(&arity, &value_ident);
+
+                variants.push(quote!(#field_name_pascal(#field_type)));
+                functions.push(quote! {
+                    Self::#field_name_pascal(#value_ident) => (#field_name_snake::NAME, #value)
+                });
+
+                let null_fns = arity.is_optional().then(|| {
+                    let null_variant = format_ident!("{field_name_pascal}Null");
+
+                    variants.push(quote!(#null_variant(NullableJsonNullValueInput)));
+                    functions.push(quote! {
+                        Self::#null_variant(value) => (
+                            #field_name_snake::NAME,
+                            #pcr::PrismaValue::Enum(value.to_string())
+                        )
+                    });
+
+                    quote! {
+                        pub fn set_null(value: NullableJsonNullValueInput) -> SetParam {
+                            SetParam::#null_variant(value)
+                        }
+                    }
+                });
+
+                (
+                    field.name().to_string(),
+                    quote! {
+                        pub struct Set(pub #field_type);
+
+                        impl From<Set> for SetParam {
+                            fn from(Set(v): Set) -> Self {
+                                Self::#field_name_pascal(v)
+                            }
+                        }
+
+                        pub fn set<T: From<Set>>(value: #field_type) -> T {
+                            Set(value).into()
+                        }
+
+                        #null_fns
+                    },
+                )
+            }
             _ => {
                 if let Some(write_param) = args.write_param(scalar_field) {
                     let param_enum = write_params::enum_name(write_param);
@@ -390,16 +465,37 @@ fn field_set_params(
 		                    let method_name_snake = snake_ident(&field.name);
 		                    let method_name_pascal = pascal_ident(&field.name);
 
-		                    let typ = field.type_tokens(&quote!());
+		                    let typ = field.type_tokens(&quote!(), args.bigint_type);
+
+		                    let push_many_fn = write_params::push_many_type(field, &quote!(), args.bigint_type).map(|(typ, _)| quote! {
+			                    pub fn push_many<T: From<UpdateOperation>>(value: #typ) -> T {
+				                    UpdateOperation(#param_enum_path::PushMany(value)).into()
+			                    }
+		                    });
 
 		                    Some(quote! {
 			                    pub fn #method_name_snake<T: From<UpdateOperation>>(value: #typ) -> T {
 				                    UpdateOperation(#param_enum_path::#method_name_pascal(value)).into()
 			                    }
+
+			                    #push_many_fn
 		                    })
 	                    })
 	                    .collect::<TokenStream>();
 
+                    // lets IDs be known before the record is created
+                    let generate_fn = crate::generator::id_generator(scalar_field)
+                        .and_then(Result::ok)
+                        .map(|generator| {
+                            let generator = format_ident!("{}", generator);
+
+                            quote! {
+                                pub fn generate() -> String {
+                                    #pcr::ids::#generator()
+                                }
+                            }
+                        });
+
                     let impl_from_for_set_param =
                         (!scalar_field.is_in_required_relation()).then(|| {
                             quote! {
@@ -441,6 +537,8 @@ fn field_set_params(
                             pub struct UpdateOperation(pub #param_enum_path);
 
                             #other_fns
+
+                            #generate_fn
                         },
                     )
                 } else {
@@ -584,7 +682,190 @@ fn field_set_params(
             variants.extend(v);
             functions.extend(f);
 
-            (field.name().to_string(), base)
+            let connect_or_create_variant = format_ident!("ConnectOrCreate{field_name_pascal}");
+
+            let connect_or_create_value = quote! {
+                #pcr::PrismaValue::Object(vec![
+                    (
+                        "where".to_string(),
+                        #pcr::PrismaValue::Object(
+                            [_where]
+                                .into_iter()
+                                .map(Into::<super::#relation_model_name_snake::WhereParam>::into)
+                                .map(#pcr::WhereInput::serialize)
+                                .map(#pcr::SerializedWhereInput::transform_equals)
+                                .collect()
+                        )
+                    ),
+                    (
+                        "create".to_string(),
+                        #pcr::PrismaValue::Object(create.into_iter().map(Into::into).collect())
+                    ),
+                ])
+            };
+
+            let connect_or_create_fn = match arity {
+                FieldArity::List => {
+                    variants.push(quote!(#connect_or_create_variant(Vec<(
+                        super::#relation_model_name_snake::UniqueWhereParam,
+                        Vec<super::#relation_model_name_snake::SetParam>
+                    )>)));
+                    functions.push(quote! {
+                        Self::#connect_or_create_variant(values) => (
+                            #field_name_snake::NAME,
+                            #pcr::PrismaValue::Object(vec![(
+                                "connectOrCreate".to_string(),
+                                #pcr::PrismaValue::List(
+                                    values
+                                        .into_iter()
+                                        .map(|(_where, create)| #connect_or_create_value)
+                                        .collect()
+                                )
+                            )])
+                        )
+                    });
+
+                    quote! {
+                        pub fn connect_or_create(
+                            values: Vec<(
+                                #relation_model_name_snake::UniqueWhereParam,
+                                Vec<#relation_model_name_snake::SetParam>
+                            )>
+                        ) -> SetParam {
+                            SetParam::#connect_or_create_variant(values)
+                        }
+                    }
+                }
+                _ => {
+                    variants.push(quote!(#connect_or_create_variant(
+                        super::#relation_model_name_snake::UniqueWhereParam,
+                        Vec<super::#relation_model_name_snake::SetParam>
+                    )));
+                    functions.push(quote! {
+                        Self::#connect_or_create_variant(_where, create) => (
+                            #field_name_snake::NAME,
+                            #pcr::PrismaValue::Object(vec![(
+                                "connectOrCreate".to_string(),
+                                #connect_or_create_value
+                            )])
+                        )
+                    });
+
+                    quote! {
+                        pub fn connect_or_create(
+                            _where: #relation_model_name_snake::UniqueWhereParam,
+                            create: Vec<#relation_model_name_snake::SetParam>
+                        ) -> SetParam {
+                            SetParam::#connect_or_create_variant(_where, create)
+                        }
+                    }
+                }
+            };
+
+            let create_many_fn = supports_nested_create_many(relation_field, args).then(|| {
+                let create_many_variant = format_ident!("CreateMany{field_name_pascal}");
+
+                variants.push(quote!(#create_many_variant(
+                    Vec<Vec<super::#relation_model_name_snake::UncheckedSetParam>>
+                )));
+                functions.push(quote! {
+                    Self::#create_many_variant(data) => (
+                        #field_name_snake::NAME,
+                        #pcr::PrismaValue::Object(vec![(
+                            "createMany".to_string(),
+                            #pcr::PrismaValue::Object(vec![(
+                                "data".to_string(),
+                                #pcr::PrismaValue::List(
+                                    data
+                                        .into_iter()
+                                        .map(|params| #pcr::PrismaValue::Object(
+                                            params.into_iter().map(Into::into).collect()
+                                        ))
+                                        .collect()
+                                )
+                            )])
+                        )])
+                    )
+                });
+
+                quote! {
+                    pub fn create_many(
+                        data: Vec<Vec<#relation_model_name_snake::UncheckedSetParam>>
+                    ) -> SetParam {
+                        SetParam::#create_many_variant(data)
+                    }
+                }
+            });
+
+            let many_fns = arity.is_list().then(|| {
+                let update_many_variant = format_ident!("UpdateMany{field_name_pascal}");
+                let delete_many_variant = format_ident!("DeleteMany{field_name_pascal}");
+
+                let where_value = quote! {
+                    #pcr::PrismaValue::Object(#pcr::merge_fields(
+                        _where
+                            .into_iter()
+                            .map(#pcr::WhereInput::serialize)
+                            .map(Into::into)
+                            .collect()
+                    ))
+                };
+
+                variants.push(quote!(#update_many_variant(
+                    Vec<super::#relation_model_name_snake::WhereParam>,
+                    Vec<super::#relation_model_name_snake::UncheckedSetParam>
+                )));
+                variants.push(quote!(#delete_many_variant(
+                    Vec<super::#relation_model_name_snake::WhereParam>
+                )));
+                functions.push(quote! {
+                    Self::#update_many_variant(_where, data) => (
+                        #field_name_snake::NAME,
+                        #pcr::PrismaValue::Object(vec![(
+                            "updateMany".to_string(),
+                            #pcr::PrismaValue::Object(vec![
+                                ("where".to_string(), #where_value),
+                                (
+                                    "data".to_string(),
+                                    #pcr::PrismaValue::Object(data.into_iter().map(Into::into).collect())
+                                ),
+                            ])
+                        )])
+                    )
+                });
+                functions.push(quote! {
+                    Self::#delete_many_variant(_where) => (
+                        #field_name_snake::NAME,
+                        #pcr::PrismaValue::Object(vec![(
+                            "deleteMany".to_string(),
+                            #where_value
+                        )])
+                    )
+                });
+
+                quote! {
+                    pub fn update_many(
+                        _where: Vec<#relation_model_name_snake::WhereParam>,
+                        data: Vec<#relation_model_name_snake::UncheckedSetParam>
+                    ) -> SetParam {
+                        SetParam::#update_many_variant(_where, data)
+                    }
+
+                    pub fn delete_many(_where: Vec<#relation_model_name_snake::WhereParam>) -> SetParam {
+                        SetParam::#delete_many_variant(_where)
+                    }
+                }
+            });
+
+            (
+                field.name().to_string(),
+                quote! {
+                    #base
+                    #connect_or_create_fn
+                    #create_many_fn
+                    #many_fns
+                },
+            )
         }
     };
 
@@ -658,6 +939,34 @@ pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
                         ))
                     }
                     ScalarFieldType::Unsupported(_) => return None,
+                    ScalarFieldType::BuiltInScalar(ScalarType::Json)
+                        if !field.ast_field().arity.is_list() =>
+                    {
+                        let typ = field.type_tokens(&quote!(), args.bigint_type)?;
+
+                        let value_ident = format_ident!("value");
+                        let value = json_set_value(&field.ast_field().arity, &value_ident);
+
+                        (
+                            (
+                                quote!(#field_name_pascal(#typ)),
+                                quote! {
+                                    Self::#field_name_pascal(#value_ident) =>
+                                        (#field_name_str, #value)
+                                },
+                            ),
+                            (
+                                field.name().to_string(),
+                                quote! {
+                                    impl From<Set> for UncheckedSetParam {
+                                        fn from(Set(v): Set) -> Self {
+                                            Self::#field_name_pascal(v)
+                                        }
+                                    }
+                                },
+                            ),
+                        )
+                    }
                     _ => args.write_param(field).map(|write_param| {
                         let param_enum = write_params::enum_name(write_param);
                         let param_enum_path = quote!(_prisma::write_params::#param_enum);
@@ -695,7 +1004,7 @@ pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
 
         (
             quote! {
-                #[derive(Clone)]
+                #[derive(Debug, Clone)]
                 pub enum UncheckedSetParam {
                       #(#variants),*
                 }
@@ -716,7 +1025,7 @@ pub fn model_data(model: ModelWalker, args: &GenerateArgs) -> ModelModulePart {
 
     ModelModulePart {
         data: quote! {
-            #[derive(Clone)]
+            #[derive(Debug, Clone)]
             pub enum SetParam {
                 #(#variants),*
             }
@@ -4,7 +4,13 @@ use prisma_client_rust_sdk::prisma::{
 
 use crate::generator::prelude::*;
 
-fn scalar_selections_fn(model: ModelWalker, module_path: &TokenStream) -> TokenStream {
+use super::{relation_count, where_params};
+
+fn scalar_selections_fn(
+    model: ModelWalker,
+    args: &GenerateArgs,
+    module_path: &TokenStream,
+) -> TokenStream {
     let pcr = quote!(::prisma_client_rust);
 
     let selections = model.scalar_fields().flat_map(|field| {
@@ -21,7 +27,7 @@ fn scalar_selections_fn(model: ModelWalker, module_path: &TokenStream) -> TokenS
                 }
             }
             _ => {
-                field.type_tokens(module_path)?;
+                field.type_tokens(module_path, args.bigint_type)?;
                 quote!(#pcr::sel(#field_name_snake::NAME))
             }
         })
@@ -34,13 +40,75 @@ fn scalar_selections_fn(model: ModelWalker, module_path: &TokenStream) -> TokenS
     }
 }
 
-pub fn r#struct(model: ModelWalker, module_path: &TokenStream) -> TokenStream {
+/// Any unique criteria of required fields can point at a record,
+/// and Prisma requires every model to have at least one.
+fn cursor_fn(model: ModelWalker) -> TokenStream {
+    let cursor = where_params::unique_field_combos(model)
+        .into_iter()
+        .find(|fields| {
+            fields
+                .iter()
+                .all(|field| field.ast_field().arity.is_required())
+        })
+        .map(|fields| {
+            let variant_name = format_ident!(
+                "{}Equals",
+                fields
+                    .iter()
+                    .map(|field| pascal_ident(field.name()).to_string())
+                    .collect::<String>()
+            );
+            let field_names_snake = fields.iter().map(|field| snake_ident(field.name()));
+
+            quote!(UniqueWhereParam::#variant_name(#(data.#field_names_snake.clone()),*))
+        })
+        .unwrap_or_else(|| quote!(unreachable!("Models always have a unique criteria")));
+
+    quote! {
+        fn cursor(data: &Self::Data) -> Self::Cursor {
+            #cursor
+        }
+    }
+}
+
+fn with_selections_fn(model: ModelWalker) -> Option<TokenStream> {
     let pcr = quote!(::prisma_client_rust);
 
-    let scalar_selections_fn = scalar_selections_fn(model, module_path);
+    // relation counts are merged into a single `_count` selection
+    (!relation_count::fields(model).is_empty()).then(|| {
+        quote! {
+            fn with_selections(params: Vec<WithParam>) -> Vec<#pcr::Selection> {
+                let (counts, params): (Vec<_>, Vec<_>) = params
+                    .into_iter()
+                    .partition(|param| matches!(param, WithParam::Count(_)));
+
+                let counts = counts
+                    .into_iter()
+                    .filter_map(|param| match param {
+                        WithParam::Count(count) => Some(count),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                params
+                    .into_iter()
+                    .map(Into::into)
+                    .chain((!counts.is_empty()).then(|| CountParam::to_count_selection(counts)))
+                    .collect()
+            }
+        }
+    })
+}
+
+pub fn r#struct(model: ModelWalker, args: &GenerateArgs, module_path: &TokenStream) -> TokenStream {
+    let pcr = quote!(::prisma_client_rust);
+
+    let scalar_selections_fn = scalar_selections_fn(model, args, module_path);
+    let cursor_fn = cursor_fn(model);
+    let with_selections_fn = with_selections_fn(model);
 
     quote! {
-        #[derive(Clone)]
+        #[derive(Debug, Clone)]
         pub struct Types;
 
         impl #pcr::ModelTypes for Types {
@@ -51,10 +119,20 @@ pub fn r#struct(model: ModelWalker, module_path: &TokenStream) -> TokenStream {
             type With = WithParam;
             type OrderBy = OrderByWithRelationParam;
             type Cursor = UniqueWhereParam;
+            type Aggregate = AggregateParam;
+            type AggregateData = AggregateData;
+            type ScalarField = ScalarField;
+            type OrderByWithAggregation = OrderByWithAggregationParam;
+            type GroupByData = GroupByData;
+            type Having = HavingParam;
 
             const MODEL: &'static str = NAME;
 
             #scalar_selections_fn
+
+            #cursor_fn
+
+            #with_selections_fn
         }
     }
 }
@@ -1,12 +1,16 @@
 use prisma_client_rust_sdk::prisma::{
+    dmmf::TypeLocation,
     prisma_models::{
         walkers::{FieldWalker, ModelWalker, RefinedFieldWalker, ScalarFieldWalker},
         FieldArity,
     },
-    psl::parser_database::ScalarFieldType,
+    psl::parser_database::{ScalarFieldType, ScalarType},
 };
 
-use crate::generator::prelude::*;
+use crate::generator::{
+    prelude::*,
+    read_filters::{null_value_filter, INSENSITIVE_ACTIONS},
+};
 
 use super::ModelModulePart;
 
@@ -16,7 +20,7 @@ pub struct Operator {
     pub list: bool,
 }
 
-static OPERATORS: &'static [Operator] = &[
+pub static OPERATORS: &'static [Operator] = &[
     Operator {
         name: "Not",
         action: "NOT",
@@ -58,12 +62,13 @@ impl Variant {
         field: ScalarFieldWalker,
         read_filter: &Filter,
         module_path: &TokenStream,
+        bigint_type: BigIntType,
     ) -> Self {
         Self::UniqueVariant {
             field_name: field.name().to_string(),
             field_required_type: field
                 .scalar_field_type()
-                .to_tokens(module_path, &FieldArity::Required, field.db)
+                .to_tokens(module_path, &FieldArity::Required, field.db, bigint_type)
                 .unwrap(),
             read_filter_name: read_filter.name.to_string(),
             optional: field.ast_field().arity.is_optional(),
@@ -147,7 +152,7 @@ pub fn collate_entries(entries: Vec<Variant>) -> TokenStream {
     }).unzip();
 
     quote! {
-        #[derive(Clone)]
+        #[derive(Debug, Clone)]
         pub enum WhereParam {
             #(#variants),*
         }
@@ -162,7 +167,7 @@ pub fn collate_entries(entries: Vec<Variant>) -> TokenStream {
             }
         }
 
-        #[derive(Clone)]
+        #[derive(Debug, Clone)]
         pub enum UniqueWhereParam {
             #(#unique_variants),*
         }
@@ -244,7 +249,7 @@ pub fn model_data(
                 field
             ).unwrap();
 
-            entries.push(Variant::unique(field, read_filter, module_path));
+            entries.push(Variant::unique(field, read_filter, module_path, args.bigint_type));
 
             None
         } else {
@@ -256,15 +261,15 @@ pub fn model_data(
             let ((field_defs, field_types), (prisma_values, field_names_snake)):
                 ((Vec<_>, Vec<_>), (Vec<_>, Vec<_>)) = fields.into_iter().map(|field| {
                 let field_type = match field.ast_field().arity {
-                    FieldArity::List | FieldArity::Required => field.type_tokens(module_path),
-                    FieldArity::Optional => field.scalar_field_type().to_tokens(module_path, &FieldArity::Required, field.db)
+                    FieldArity::List | FieldArity::Required => field.type_tokens(module_path, args.bigint_type),
+                    FieldArity::Optional => field.scalar_field_type().to_tokens(module_path, &FieldArity::Required, field.db, args.bigint_type)
                 }.unwrap();
 
                 let field_name_snake = snake_ident(field.name());
 
                 (
                     (quote!(#field_name_snake: #field_type), field_type),
-                    (field.scalar_field_type().to_prisma_value(&field_name_snake, &FieldArity::Required), field_name_snake)
+                    (field.scalar_field_type().to_prisma_value(&field_name_snake, &FieldArity::Required, args.bigint_type), field_name_snake)
                 )
             }).unzip();
 
@@ -305,17 +310,176 @@ pub fn model_data(
 
     entries.extend(field_where_param_entries.into_iter().flatten());
 
+    entries.push(Variant::BaseVariant {
+        definition: quote!(Dynamic(String, #pcr::PrismaValue)),
+        match_arm: quote! {
+            Self::Dynamic(field, value) => return #pcr::SerializedWhereInput::new(
+                field,
+                #pcr::SerializedWhereValue::Value(value)
+            )
+        },
+    });
+
     let collated_entries = collate_entries(entries);
 
+    let from_json_fn = from_json_fn(model, args);
+    let search_fields_fn = search_fields_fn(model);
+
     ModelModulePart {
         data: quote! {
             #compound_field_accessors
             #collated_entries
+            #from_json_fn
+            #search_fields_fn
         },
         fields: field_stuff,
     }
 }
 
+/// Generates `search_fields`, which matches records where any of the given
+/// `String` fields contain a value
+fn search_fields_fn(model: ModelWalker) -> Option<TokenStream> {
+    let arms = model
+        .scalar_fields()
+        .filter(|field| {
+            !field.ast_field().arity.is_list()
+                && matches!(
+                    field.scalar_field_type(),
+                    ScalarFieldType::BuiltInScalar(ScalarType::String)
+                )
+        })
+        .map(|field| {
+            let field_name_pascal = pascal_ident(field.name());
+            let field_name_snake = snake_ident(field.name());
+
+            quote!(ScalarField::#field_name_pascal => Some(#field_name_snake::contains(value.to_string())))
+        })
+        .collect::<Vec<_>>();
+
+    (!arms.is_empty()).then(|| {
+        quote! {
+            /// Matches records where any of `fields` contain `value`.
+            /// Fields that aren't strings are ignored, and an empty list of fields matches nothing.
+            pub fn search_fields(fields: &[ScalarField], value: &str) -> WhereParam {
+                WhereParam::Or(
+                    fields
+                        .iter()
+                        .filter_map(|field| match field {
+                            #(#arms,)*
+                            _ => None,
+                        })
+                        .collect()
+                )
+            }
+        }
+    })
+}
+
+/// Generates `WhereParam::from_json`, which validates field and filter names
+/// against the model before passing the filters on to the engine
+fn from_json_fn(model: ModelWalker, args: &GenerateArgs) -> TokenStream {
+    let pcr = quote!(::prisma_client_rust);
+
+    let field_arms = model.fields().filter_map(|field| {
+        let field_name_str = field.name();
+
+        let filter = match field.refine() {
+            RefinedFieldWalker::Scalar(scalar_field) => {
+                let filters = args.read_filter(scalar_field)?.fields.iter().map(|field| {
+                    let name = &field.name;
+
+                    let kind = match field.input_types.iter().find(
+                        |typ| !matches!(typ.location, TypeLocation::Scalar if typ.typ == "Null"),
+                    ) {
+                        Some(typ)
+                            if matches!(typ.location, TypeLocation::Scalar)
+                                && typ.typ == "Json" =>
+                        {
+                            quote!(Json)
+                        }
+                        Some(typ) if matches!(typ.location, TypeLocation::EnumTypes) => {
+                            quote!(Enum)
+                        }
+                        _ => quote!(Value),
+                    };
+
+                    quote!((#name, #pcr::DynamicValueKind::#kind))
+                });
+
+                quote!(#pcr::dynamic_scalar_filter(&field, value, &[#(#filters),*])?)
+            }
+            RefinedFieldWalker::Relation(relation_field) => {
+                let relation_model_name_snake = snake_ident(relation_field.related_model().name());
+
+                let filters = match field.ast_field().arity {
+                    FieldArity::List => quote!(&["some", "every", "none"]),
+                    _ => quote!(&["is", "isNot"]),
+                };
+
+                quote! {
+                    #pcr::dynamic_relation_filter(
+                        &field,
+                        value,
+                        #filters,
+                        super::#relation_model_name_snake::WhereParam::from_json
+                    )?
+                }
+            }
+        };
+
+        Some(quote! {
+            #field_name_str => {
+                let value = #filter;
+                Self::Dynamic(field, value)
+            }
+        })
+    });
+
+    quote! {
+        impl WhereParam {
+            /// Builds filters from a JSON object with the same shape as Prisma's `where` input,
+            /// returning an error if a field or filter doesn't exist on the model.
+            pub fn from_json(value: #pcr::serde_json::Value) -> Result<Vec<Self>, #pcr::DynamicFilterError> {
+                let fields = match value {
+                    #pcr::serde_json::Value::Object(fields) => fields,
+                    _ => return Err(#pcr::DynamicFilterError::ExpectedObject(NAME.to_string())),
+                };
+
+                fields
+                    .into_iter()
+                    .map(|(field, value)| {
+                        Ok(match field.as_str() {
+                            "AND" => Self::And(
+                                #pcr::dynamic_filter_groups("AND", value, Self::from_json)?
+                                    .into_iter()
+                                    .flatten()
+                                    .collect()
+                            ),
+                            "OR" => Self::Or(
+                                #pcr::dynamic_filter_groups("OR", value, Self::from_json)?
+                                    .into_iter()
+                                    .map(Self::And)
+                                    .collect()
+                            ),
+                            "NOT" => Self::And(
+                                #pcr::dynamic_filter_groups("NOT", value, Self::from_json)?
+                                    .into_iter()
+                                    .map(Self::Not)
+                                    .collect()
+                            ),
+                            #(#field_arms,)*
+                            _ => return Err(#pcr::DynamicFilterError::UnknownField {
+                                model: NAME,
+                                field,
+                            }),
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
 pub fn unique_field_combos(model: ModelWalker) -> Vec<Vec<ScalarFieldWalker>> {
     let mut combos = model
         .indexes()
@@ -371,7 +535,7 @@ pub fn field_module(
     let field_name = field.name();
     let field_name_pascal = pascal_ident(field_name);
     let field_name_snake = snake_ident(field_name);
-    let field_type = field.type_tokens(&quote!());
+    let field_type = field.type_tokens(&quote!(), args.bigint_type);
 
     let is_null_variant = format_ident!("{field_name_pascal}IsNull");
     let equals_variant = format_ident!("{field_name_pascal}Equals");
@@ -658,7 +822,7 @@ pub fn field_module(
 
 					let read_methods = read_filter.fields.iter().filter_map(|field| {
 						let name = match field.name.as_str() {
-							"equals" => return None,
+							"equals" | "path" => return None,
 							"in" => "inVec",
 							"notIn" => "notInVec",
 							n => n
@@ -667,18 +831,80 @@ pub fn field_module(
 						let method_name_snake = snake_ident(name);
 						let method_name_pascal = pascal_ident(name);
 
-						let typ = field.type_tokens(&quote!());
+						let typ = field.type_tokens(&quote!(), args.bigint_type);
 
 						Some(quote!(fn #method_name_snake(_: #typ) -> #method_name_pascal;))
 					});
 
+					let has_mode = read_filter.fields.iter().any(|field| field.name == "mode");
+
+					let insensitive_methods = read_filter.fields.iter().filter(|field| {
+						has_mode && INSENSITIVE_ACTIONS.contains(&field.name.as_str())
+					}).map(|field| {
+						let method_name_snake = format_ident!("{}_insensitive", snake_ident(&field.name));
+						let method_name_pascal = format_ident!("{}Insensitive", pascal_ident(&field.name));
+
+						let typ = field.type_tokens(&quote!(), args.bigint_type);
+
+						quote!(fn #method_name_snake(_: #typ) -> #method_name_pascal;)
+					});
+
+					let null_value_methods = read_filter.fields.iter().filter_map(|field| {
+						let enum_name = format_ident!("{}", null_value_filter(field)?);
+
+						let method_name_snake = format_ident!("{}_null", snake_ident(&field.name));
+						let method_name_pascal = format_ident!("{}Null", pascal_ident(&field.name));
+
+						Some(quote!(fn #method_name_snake(_: #enum_name) -> #method_name_pascal;))
+					});
+
+					// JSON filters are applied to the value at `path` in the same filter object,
+					// so `path` returns a builder for the filter to apply rather than a `WhereParam`
+					let path_filter = read_filter.fields.iter().find(|field| field.name == "path").map(|path_field| {
+						let path_type = path_field.type_tokens(&quote!(), args.bigint_type);
+
+						let path_methods = read_filter.fields.iter().filter(|field| field.name != "path").map(|field| {
+							let method_name_snake = snake_ident(&field.name);
+							let method_name_pascal = pascal_ident(&field.name);
+
+							let typ = field.type_tokens(&quote!(), args.bigint_type);
+							let typ = match (read_filter.name.as_str(), field.name.as_str()) {
+								("JsonNullable", "equals") => quote!(Option<#typ>),
+								_ => typ
+							};
+
+							quote! {
+								pub fn #method_name_snake(self, value: #typ) -> WhereParam {
+									WhereParam::#field_name_pascal(_prisma::read_filters::#filter_enum::WithPath(
+										self.0,
+										vec![_prisma::read_filters::#filter_enum::#method_name_pascal(value)]
+									))
+								}
+							}
+						});
+
+						quote! {
+							pub struct Path(#path_type);
+
+							pub fn path(path: #path_type) -> Path {
+								Path(path)
+							}
+
+							impl Path {
+								#(#path_methods)*
+							}
+						}
+					});
+
 					quote! {
 						#equals
 
+						#path_filter
+
 						#pcr::scalar_where_param_fns!(
 							_prisma::read_filters::#filter_enum,
 							#field_name_pascal,
-							{ #(#read_methods)* }
+							{ #(#read_methods)* #(#insensitive_methods)* #(#null_value_methods)* }
 						);
 					}
 				});
@@ -5,7 +5,7 @@ use prisma_client_rust_sdk::prisma::prisma_models::{
 
 use crate::generator::prelude::*;
 
-use super::{order_by, pagination, ModelModulePart};
+use super::{order_by, pagination, relation_count, ModelModulePart};
 
 pub fn builder_fn(field: RelationFieldWalker) -> TokenStream {
     let relation_model_name_snake = snake_ident(field.related_model().name());
@@ -51,7 +51,7 @@ fn into_selection_arm(field: RelationFieldWalker) -> TokenStream {
         },
         _ => quote! {
             let mut selections = <super::#relation_model_name_snake::Types as #pcr::ModelTypes>::scalar_selections();
-            selections.extend(args.with_params.into_iter().map(Into::<#pcr::Selection>::into));
+            selections.extend(<super::#relation_model_name_snake::Types as #pcr::ModelTypes>::with_selections(args.with_params));
 
             #pcr::Selection::new(
                 #field_name_snake::NAME,
@@ -73,6 +73,22 @@ pub fn model_data(model: ModelWalker) -> ModelModulePart {
     let variants = model.relation_fields().map(enum_variant);
     let into_selection_arms = model.relation_fields().map(into_selection_arm);
 
+    let has_relation_counts = !relation_count::fields(model).is_empty();
+
+    let count_variant = has_relation_counts.then(|| quote!(Count(CountParam)));
+    let count_into_selection_arm = has_relation_counts.then(|| {
+        quote!(Self::Count(param) => CountParam::to_count_selection([param]))
+    });
+    let count_from_impl = has_relation_counts.then(|| {
+        quote! {
+            impl From<CountParam> for WithParam {
+                fn from(param: CountParam) -> Self {
+                    Self::Count(param)
+                }
+            }
+        }
+    });
+
     let fields = model
         .relation_fields()
         .map(|field| {
@@ -95,6 +111,11 @@ pub fn model_data(model: ModelWalker) -> ModelModulePart {
                             #order_by_fn
 
                             #pagination_fns
+
+                            pub fn distinct(mut self, fields: Vec<#relation_model_name_snake::ScalarField>) -> Self {
+                                self.0 = self.0.distinct(fields);
+                                self
+                            }
                         }
 
                         impl From<Fetch> for WithParam {
@@ -135,18 +156,22 @@ pub fn model_data(model: ModelWalker) -> ModelModulePart {
 
     ModelModulePart {
         data: quote! {
-            #[derive(Clone)]
+            #[derive(Debug, Clone)]
             pub enum WithParam {
-                #(#variants),*
+                #(#variants,)*
+                #count_variant
             }
 
             impl Into<::prisma_client_rust::Selection> for WithParam {
                 fn into(self) -> ::prisma_client_rust::Selection {
                     match self {
-                        #(#into_selection_arms),*
+                        #(#into_selection_arms,)*
+                        #count_into_selection_arm
                     }
                 }
             }
+
+            #count_from_impl
         },
         fields,
     }
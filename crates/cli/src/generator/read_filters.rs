@@ -1,5 +1,121 @@
+use prisma_client_rust_sdk::prisma::dmmf::{DmmfInputField, TypeLocation};
+
 use super::prelude::*;
 
+/// String filter actions that can be combined with `mode: insensitive`
+pub const INSENSITIVE_ACTIONS: &[&str] = &["equals", "contains", "startsWith", "endsWith"];
+
+/// Generates `<Action>Insensitive` variants for filters that support a query mode,
+/// which serialize to `{ <action>: value, mode: insensitive }`.
+fn insensitive_variants<'a>(
+    fields: &'a [&'a DmmfInputField],
+    bigint_type: BigIntType,
+) -> impl Iterator<Item = (TokenStream, TokenStream)> + 'a {
+    let has_mode = fields.iter().any(|field| field.name == "mode");
+
+    fields
+        .iter()
+        .filter(move |field| has_mode && INSENSITIVE_ACTIONS.contains(&field.name.as_str()))
+        .map(|field| {
+            let action_str = &field.name;
+            let variant_name = format_ident!("{}Insensitive", pascal_ident(action_str));
+
+            let value_ident = format_ident!("value");
+
+            let value_as_prisma_value = field.to_prisma_value(&value_ident, bigint_type);
+
+            let typ = field.type_tokens(&quote!(super::super::), bigint_type);
+
+            (
+                quote!(#variant_name(#typ)),
+                quote! {
+                    Self::#variant_name(#value_ident) =>
+                        ::prisma_client_rust::SerializedWhereValue::Object(
+                            vec![
+                                (
+                                    #action_str.to_string(),
+                                    #value_as_prisma_value
+                                ),
+                                (
+                                    "mode".to_string(),
+                                    ::prisma_client_rust::PrismaValue::Enum("insensitive".to_string())
+                                )
+                            ]
+                        )
+                },
+            )
+        })
+}
+
+/// Finds the enum of null values that a filter accepts as an alternative to its scalar value,
+/// eg. `JsonNullValueFilter` for distinguishing database `NULL` from JSON `null`
+pub fn null_value_filter(field: &DmmfInputField) -> Option<&str> {
+    field
+        .input_types
+        .iter()
+        .find(|typ| {
+            matches!(typ.location, TypeLocation::EnumTypes) && typ.typ.ends_with("NullValueFilter")
+        })
+        .map(|typ| typ.typ.as_str())
+}
+
+/// Generates `<Action>Null` variants for filters that accept a null value enum,
+/// which serialize to `{ <action>: DbNull | JsonNull | AnyNull }`.
+fn null_value_variants<'a>(
+    fields: &'a [&'a DmmfInputField],
+) -> impl Iterator<Item = (TokenStream, TokenStream)> + 'a {
+    fields.iter().filter_map(|field| {
+        let enum_name = format_ident!("{}", null_value_filter(field)?);
+
+        let action_str = &field.name;
+        let variant_name = format_ident!("{}Null", pascal_ident(action_str));
+
+        Some((
+            quote!(#variant_name(super::super::#enum_name)),
+            quote! {
+                Self::#variant_name(value) =>
+                    ::prisma_client_rust::SerializedWhereValue::Object(
+                        vec![(
+                            #action_str.to_string(),
+                            ::prisma_client_rust::PrismaValue::Enum(value.to_string())
+                        )]
+                    )
+            },
+        ))
+    })
+}
+
+/// Generates a `WithPath` variant for filters that support a JSON `path`,
+/// which applies the contained filters to the value at that path.
+fn with_path_variant(
+    fields: &[&DmmfInputField],
+    bigint_type: BigIntType,
+) -> Option<(TokenStream, TokenStream)> {
+    let path_field = fields.iter().find(|field| field.name == "path")?;
+
+    let path_ident = format_ident!("path");
+
+    let path_typ = path_field.type_tokens(&quote!(super::super::), bigint_type);
+    let path_as_prisma_value = path_field.to_prisma_value(&path_ident, bigint_type);
+
+    Some((
+        quote!(WithPath(#path_typ, Vec<Self>)),
+        quote! {
+            Self::WithPath(#path_ident, filters) =>
+                ::prisma_client_rust::SerializedWhereValue::Object(
+                    std::iter::once(("path".to_string(), #path_as_prisma_value))
+                        .chain(filters.into_iter().flat_map(|filter| {
+                            match Into::<::prisma_client_rust::SerializedWhereValue>::into(filter) {
+                                ::prisma_client_rust::SerializedWhereValue::Object(entries) => entries,
+                                _ => unreachable!("Read filters always serialize to objects"),
+                            }
+                        }))
+                        .collect()
+                )
+        },
+    ))
+}
+
 pub fn generate_module(args: &GenerateArgs) -> TokenStream {
     let read_filters = args.read_filters.iter().map(|filter| {
         let name = format_ident!("{}Filter", &filter.name);
@@ -19,9 +135,9 @@ pub fn generate_module(args: &GenerateArgs) -> TokenStream {
 
                 let value_ident = format_ident!("value");
 
-                let value_as_prisma_value = field.to_prisma_value(&value_ident);
+                let value_as_prisma_value = field.to_prisma_value(&value_ident, args.bigint_type);
 
-                let typ = field.type_tokens(&quote!(super::super::));
+                let typ = field.type_tokens(&quote!(super::super::), args.bigint_type);
 
                 // https://github.com/Brendonovich/prisma-client-rust/issues/297
                 if filter.name == "JsonNullable" && field.name == "equals" {
@@ -53,10 +169,13 @@ pub fn generate_module(args: &GenerateArgs) -> TokenStream {
                     ))
                 }
             })
+            .chain(insensitive_variants(&filter.fields, args.bigint_type))
+            .chain(null_value_variants(&filter.fields))
+            .chain(with_path_variant(&filter.fields, args.bigint_type))
             .unzip();
 
         quote! {
-            #[derive(Clone)]
+            #[derive(Debug, Clone)]
             pub enum #name {
                 #(#method_variants),*
             }
@@ -1,9 +1,57 @@
+use prisma_client_rust_sdk::prisma::{
+    dmmf::{DmmfInputField, TypeLocation},
+    psl::parser_database::ScalarType,
+};
+
 use super::prelude::*;
 
 pub fn enum_name(filter: &Filter) -> Ident {
     format_ident!("{}Param", &filter.name)
 }
 
+/// Scalar list `push` operations accept either a single value or a list of values.
+/// Returns the list's type and how to convert a `value` of it to a `PrismaValue`,
+/// so that pushing multiple values can be exposed as `push_many`.
+pub fn push_many_type(
+    field: &DmmfInputField,
+    prefix: &TokenStream,
+    bigint_type: BigIntType,
+) -> Option<(TokenStream, TokenStream)> {
+    if field.name != "push" {
+        return None;
+    }
+
+    let pv = quote!(::prisma_client_rust::PrismaValue);
+    let value_ident = format_ident!("value");
+
+    let input_type = field.input_types.iter().find(|typ| typ.is_list)?;
+
+    let (typ, converter) = match input_type.location {
+        TypeLocation::Scalar => {
+            let scalar = ScalarType::try_from_str(&input_type.typ)?;
+
+            (
+                scalar.to_tokens(bigint_type),
+                scalar.to_prisma_value(&value_ident, bigint_type),
+            )
+        }
+        TypeLocation::EnumTypes => {
+            let typ: TokenStream = input_type.typ.parse().unwrap();
+
+            (
+                quote!(#prefix #typ),
+                quote!(#pv::Enum(#value_ident.to_string())),
+            )
+        }
+        _ => return None,
+    };
+
+    Some((
+        quote!(Vec<#typ>),
+        quote!(#pv::List(#value_ident.into_iter().map(|#value_ident| #converter).collect())),
+    ))
+}
+
 pub fn generate_module(args: &GenerateArgs) -> TokenStream {
     let write_params = args.write_params.iter().map(|write_param| {
         let name = enum_name(write_param);
@@ -12,10 +60,11 @@ pub fn generate_module(args: &GenerateArgs) -> TokenStream {
             .fields
             .iter()
             .flat_map(|field| {
-                let typ = field.type_tokens(&quote!(super::super::));
+                let typ = field.type_tokens(&quote!(super::super::), args.bigint_type);
                 let action = &field.name;
 
-                let prisma_value_converter = field.to_prisma_value(&format_ident!("value"));
+                let prisma_value_converter =
+                    field.to_prisma_value(&format_ident!("value"), args.bigint_type);
 
                 let method_name_pascal = pascal_ident(&field.name);
 
@@ -31,15 +80,31 @@ pub fn generate_module(args: &GenerateArgs) -> TokenStream {
                     }
                 };
 
-                Some((
-                    quote!(#method_name_pascal(#typ)),
-                    quote!(Self::#method_name_pascal(value) => #prisma_value),
-                ))
+                let push_many = push_many_type(field, &quote!(super::super::), args.bigint_type).map(|(typ, converter)| {
+                    (
+                        quote!(PushMany(#typ)),
+                        quote! {
+                            Self::PushMany(value) => ::prisma_client_rust::PrismaValue::Object(vec![(
+                                #action.to_string(),
+                                #converter
+                            )])
+                        },
+                    )
+                });
+
+                [
+                    Some((
+                        quote!(#method_name_pascal(#typ)),
+                        quote!(Self::#method_name_pascal(value) => #prisma_value),
+                    )),
+                    push_many,
+                ]
             })
+            .flatten()
             .unzip();
 
         quote! {
-            #[derive(Clone)]
+            #[derive(Debug, Clone)]
             pub enum #name {
                 #(#method_variants),*
             }
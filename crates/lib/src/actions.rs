@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{ModelWriteOperation, SerializedWhereInput};
 
 pub trait WhereInput {
@@ -12,14 +14,46 @@ pub struct ModelMutationCallbackData {
 
 pub type ModelMutationCallback = Box<dyn Fn(ModelMutationCallbackData) + Sync + Send>;
 
+/// Information about a query that has been executed, passed to query callbacks
+#[derive(Debug, Clone)]
+pub struct QueryEvent {
+    /// The name of the operation, eg. `findManyPost`
+    pub operation: String,
+    /// The operation in Prisma's query document syntax, including its arguments
+    pub query: String,
+    pub duration: Duration,
+    pub success: bool,
+}
+
+pub type QueryCallback = Box<dyn Fn(&QueryEvent) + Sync + Send>;
+
+/// Logs a query as being slow with a `tracing` warning
+#[cfg(feature = "tracing")]
+pub fn log_slow_query(event: &QueryEvent) {
+    tracing::warn!(
+        operation = %event.operation,
+        duration = ?event.duration,
+        query = %event.query,
+        "Slow query"
+    );
+}
+
 pub struct ActionNotifier {
     pub model_mutation_callbacks: Vec<ModelMutationCallback>,
+    pub query_callbacks: Vec<QueryCallback>,
 }
 
 impl ActionNotifier {
     pub fn new() -> Self {
         Self {
             model_mutation_callbacks: vec![],
+            query_callbacks: vec![],
+        }
+    }
+
+    pub(crate) fn notify_query(&self, event: QueryEvent) {
+        for callback in &self.query_callbacks {
+            callback(&event);
         }
     }
 }
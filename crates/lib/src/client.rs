@@ -6,10 +6,18 @@ use query_core::{
     BatchDocumentTransaction, CoreError, Operation, TxId,
 };
 
-use std::sync::Arc;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 use thiserror::Error;
 
-use crate::{prisma_value, QueryError, Result};
+use crate::{
+    operation_to_string, prisma_value, QueryError, QueryEvent, QueryRaw, Raw, Result, RunCommandRaw,
+};
 
 pub type Executor = Box<dyn query_core::QueryExecutor + Send + Sync + 'static>;
 
@@ -23,6 +31,23 @@ pub struct ExecutorConnector {
     pub executor: Executor,
     pub query_schema: Arc<QuerySchema>,
     pub url: String,
+    pub replicas: Vec<Executor>,
+    next_replica: AtomicUsize,
+}
+
+impl ExecutorConnector {
+    /// Picks the executor for an operation made outside of a transaction,
+    /// sending reads to the replicas in turn and everything else to the primary
+    fn executor_for(&self, op: &Operation, primary: bool) -> &Executor {
+        match op {
+            Operation::Read(_) if !primary && !self.replicas.is_empty() => {
+                let i = self.next_replica.fetch_add(1, Ordering::Relaxed);
+
+                &self.replicas[i % self.replicas.len()]
+            }
+            _ => &self.executor,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -30,6 +55,8 @@ pub(crate) enum ExecutionEngine {
     Real {
         connector: Arc<ExecutorConnector>,
         tx_id: Option<TxId>,
+        /// Whether reads should skip the replicas
+        primary: bool,
     },
     #[cfg(feature = "mocking")]
     Mock(crate::MockStore),
@@ -38,9 +65,18 @@ pub(crate) enum ExecutionEngine {
 impl ExecutionEngine {
     async fn execute(&self, op: Operation) -> Result<serde_value::Value> {
         match self {
-            Self::Real { connector, tx_id } => {
-                let response = connector
-                    .executor
+            Self::Real {
+                connector,
+                tx_id,
+                primary,
+            } => {
+                // transactions only exist on the primary
+                let executor = match tx_id {
+                    Some(_) => &connector.executor,
+                    None => connector.executor_for(&op, *primary),
+                };
+
+                let response = executor
                     .execute(
                         tx_id.clone(),
                         op,
@@ -67,15 +103,18 @@ impl ExecutionEngine {
     pub async fn execute_all(
         &self,
         ops: Vec<Operation>,
+        transaction: Option<BatchDocumentTransaction>,
     ) -> Result<Vec<Result<serde_value::Value>>> {
         match self {
-            Self::Real { connector, .. } => {
+            Self::Real {
+                connector, tx_id, ..
+            } => {
                 let response = connector
                     .executor
                     .execute_all(
-                        None,
+                        tx_id.clone(),
                         ops,
-                        Some(BatchDocumentTransaction::new(None)),
+                        transaction,
                         connector.query_schema.clone(),
                         None,
                         EngineProtocol::Graphql,
@@ -112,14 +151,45 @@ impl ExecutionEngine {
 
     fn with_tx_id(&self, tx_id: Option<TxId>) -> Self {
         match self {
-            Self::Real { connector, .. } => Self::Real {
+            Self::Real {
+                connector, primary, ..
+            } => Self::Real {
                 connector: connector.clone(),
                 tx_id,
+                primary: *primary,
             },
             #[cfg(feature = "mocking")]
             _ => self.clone(),
         }
     }
+
+    fn with_primary(&self) -> Self {
+        match self {
+            Self::Real {
+                connector, tx_id, ..
+            } => Self::Real {
+                connector: connector.clone(),
+                tx_id: tx_id.clone(),
+                primary: true,
+            },
+            #[cfg(feature = "mocking")]
+            _ => self.clone(),
+        }
+    }
+}
+
+/// Options for connecting to the database when a client is built
+#[derive(Debug, Clone, Default)]
+pub struct ConnectOptions {
+    /// How long to wait for each connection attempt, or forever if `None`
+    pub timeout: Option<Duration>,
+    /// How many times to retry connecting after the first attempt fails
+    pub retries: u32,
+    /// Skips checking the connection when the client is built,
+    /// leaving the connection to be established by the first query
+    pub lazy: bool,
+    /// URLs of read replicas that reads outside of transactions are sent to
+    pub replica_urls: Vec<String>,
 }
 
 /// The data held by the generated PrismaClient
@@ -132,7 +202,78 @@ pub struct PrismaClientInternals {
 
 impl PrismaClientInternals {
     pub(crate) async fn execute(&self, operation: Operation) -> Result<serde_value::Value> {
-        self.engine.execute(operation).await
+        #[cfg(feature = "tracing")]
+        let span = operation_span(&operation);
+
+        // rendering the query is skipped if there are no callbacks to receive it
+        let details = (!self.action_notifier.query_callbacks.is_empty()).then(|| {
+            (
+                operation_name(&operation).to_string(),
+                operation_to_string(&operation),
+            )
+        });
+
+        let future = self.engine.execute(operation);
+
+        // the engine's own spans are created inside this span, linking them to the caller's trace
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span);
+
+        let start = Instant::now();
+        let result = future.await;
+
+        if let Some((operation, query)) = details {
+            self.action_notifier.notify_query(QueryEvent {
+                operation,
+                query,
+                duration: start.elapsed(),
+                success: result.is_ok(),
+            });
+        }
+
+        result
+    }
+
+    pub(crate) async fn execute_all(
+        &self,
+        operations: Vec<Operation>,
+        transaction: Option<BatchDocumentTransaction>,
+    ) -> Result<Vec<Result<serde_value::Value>>> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "prisma_client_rust::batch",
+            db.system = "prisma",
+            db.operation = "batch",
+            operations = operations.len(),
+            transaction = transaction.is_some(),
+        );
+
+        let query = (!self.action_notifier.query_callbacks.is_empty()).then(|| {
+            operations
+                .iter()
+                .map(operation_to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+        let future = self.engine.execute_all(operations, transaction);
+
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span);
+
+        let start = Instant::now();
+        let result = future.await;
+
+        if let Some(query) = query {
+            self.action_notifier.notify_query(QueryEvent {
+                operation: "batch".to_string(),
+                query,
+                duration: start.elapsed(),
+                success: matches!(&result, Ok(results) if results.iter().all(|r| r.is_ok())),
+            });
+        }
+
+        result
     }
 
     // pub fn notify_model_mutation<'a, Action>(&self)
@@ -158,6 +299,7 @@ impl PrismaClientInternals {
         url: Option<String>,
         action_notifier: ActionNotifier,
         datamodel: &str,
+        connect_options: ConnectOptions,
     ) -> std::result::Result<Self, NewClientError> {
         let schema = Arc::new(psl::validate(datamodel.into()));
         let config = &schema.configuration;
@@ -193,7 +335,20 @@ impl PrismaClientInternals {
         let executor =
             request_handlers::load_executor(source, config.preview_features(), &url).await?;
 
-        executor.primary_connector().get_connection().await?;
+        let mut replicas = vec![];
+
+        for replica_url in &connect_options.replica_urls {
+            replicas.push(
+                request_handlers::load_executor(source, config.preview_features(), replica_url)
+                    .await?,
+            );
+        }
+
+        if !connect_options.lazy {
+            for executor in std::iter::once(&executor).chain(&replicas) {
+                check_connection(executor, &connect_options).await?;
+            }
+        }
 
         Ok(Self {
             engine: ExecutionEngine::Real {
@@ -201,8 +356,11 @@ impl PrismaClientInternals {
                     executor,
                     query_schema: Arc::new(schema::build(schema.clone(), true)),
                     url,
+                    replicas,
+                    next_replica: AtomicUsize::new(0),
                 }),
                 tx_id: None,
+                primary: false,
             },
             action_notifier: Arc::new(action_notifier),
         })
@@ -221,6 +379,29 @@ impl PrismaClientInternals {
         )
     }
 
+    /// Checks that the primary database can be queried by executing a trivial query through the engine,
+    /// returning a `PoolTimeout` error if the pool is exhausted
+    /// or an error such as `DatabaseNotReachable` if the database can't be connected to.
+    pub async fn ping(&self) -> Result<()> {
+        let primary = self.with_primary();
+
+        match &self.engine {
+            #[cfg(feature = "mocking")]
+            ExecutionEngine::Mock(_) => Ok(()),
+            _ if self.url().starts_with("mongodb") => {
+                RunCommandRaw::<serde_json::Value>::new(&primary, serde_json::json!({ "ping": 1 }))
+                    .exec()
+                    .await
+                    .map(|_| ())
+            }
+            // the query has no parameters, so the database doesn't need to be known
+            _ => QueryRaw::<serde_json::Value>::new(&primary, Raw::new("SELECT 1", vec![]), "")
+                .exec()
+                .await
+                .map(|_| ()),
+        }
+    }
+
     pub fn url(&self) -> &str {
         match &self.engine {
             #[cfg(feature = "mocking")]
@@ -229,12 +410,75 @@ impl PrismaClientInternals {
         }
     }
 
+    /// Whether queries are executed inside an interactive transaction
+    pub fn in_transaction(&self) -> bool {
+        matches!(self.engine, ExecutionEngine::Real { tx_id: Some(_), .. })
+    }
+
     pub fn with_tx_id(&self, tx_id: Option<TxId>) -> Self {
         Self {
             engine: self.engine.with_tx_id(tx_id),
             action_notifier: self.action_notifier.clone(),
         }
     }
+
+    /// Creates internals that send all operations to the primary database, ignoring any replicas
+    pub fn with_primary(&self) -> Self {
+        Self {
+            engine: self.engine.with_primary(),
+            action_notifier: self.action_notifier.clone(),
+        }
+    }
+}
+
+async fn check_connection(
+    executor: &Executor,
+    connect_options: &ConnectOptions,
+) -> std::result::Result<(), NewClientError> {
+    let mut attempt = 0;
+
+    // retrying allows services to start before the database is ready
+    loop {
+        let connection = executor.primary_connector().get_connection();
+
+        let result = match connect_options.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, connection)
+                .await
+                .map_err(|_| NewClientError::ConnectTimeout(timeout))
+                .and_then(|r| r.map_err(Into::into)),
+            None => connection.await.map_err(Into::into),
+        };
+
+        match result {
+            Ok(_) => return Ok(()),
+            Err(_) if attempt < connect_options.retries => {
+                tokio::time::sleep(Duration::from_millis(100 << attempt.min(6))).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn operation_name(operation: &Operation) -> &str {
+    match operation {
+        Operation::Read(selection) | Operation::Write(selection) => selection.name(),
+    }
+}
+
+#[cfg(feature = "tracing")]
+fn operation_span(operation: &Operation) -> tracing::Span {
+    let kind = match operation {
+        Operation::Read(_) => "read",
+        Operation::Write(_) => "write",
+    };
+
+    tracing::info_span!(
+        "prisma_client_rust::operation",
+        db.system = "prisma",
+        db.operation = operation_name(operation),
+        kind,
+    )
 }
 
 trait DiagnosticsToString {
@@ -258,6 +502,9 @@ pub enum NewClientError {
 
     #[error("Error getting database connection: {0}")]
     Connection(#[from] query_core::ConnectorError),
+
+    #[error("Timed out getting database connection after {0:?}")]
+    ConnectTimeout(Duration),
 }
 
 impl From<Diagnostics> for NewClientError {
@@ -0,0 +1,143 @@
+use bigdecimal::{BigDecimal, FromPrimitive};
+use prisma_models::PrismaValue;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{merge_fields, WhereInput};
+
+#[derive(Debug, Error)]
+pub enum DynamicFilterError {
+    #[error("Expected {0} to be an object")]
+    ExpectedObject(String),
+
+    #[error("Unknown field '{field}' on model '{model}'")]
+    UnknownField { model: &'static str, field: String },
+
+    #[error("Unknown filter '{filter}' for field '{field}'")]
+    UnknownFilter { field: String, filter: String },
+}
+
+/// How the JSON value of a filter is converted to a `PrismaValue`.
+/// Type checking of the converted values is left to the query engine.
+#[derive(Clone, Copy, Debug)]
+pub enum DynamicValueKind {
+    Value,
+    Json,
+    Enum,
+}
+
+impl DynamicValueKind {
+    pub fn convert(self, value: Value) -> PrismaValue {
+        match (self, value) {
+            (Self::Json, value) => PrismaValue::Json(value.to_string()),
+            (_, Value::Null) => PrismaValue::Null,
+            (_, Value::Bool(value)) => PrismaValue::Boolean(value),
+            (_, Value::Number(value)) => match value.as_i64() {
+                Some(value) => PrismaValue::Int(value),
+                None => PrismaValue::Float(
+                    value
+                        .as_f64()
+                        .and_then(BigDecimal::from_f64)
+                        .unwrap_or_default()
+                        .normalized(),
+                ),
+            },
+            (Self::Enum, Value::String(value)) => PrismaValue::Enum(value),
+            (Self::Value, Value::String(value)) => PrismaValue::String(value),
+            (_, Value::Array(values)) => {
+                PrismaValue::List(values.into_iter().map(|v| self.convert(v)).collect())
+            }
+            (_, Value::Object(fields)) => PrismaValue::Object(
+                fields
+                    .into_iter()
+                    .map(|(k, v)| (k, self.convert(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Converts the JSON filters for a scalar field into a `where` value,
+/// checking that each filter is one of `filters`.
+/// Values that aren't objects are treated as an `equals` filter.
+pub fn dynamic_scalar_filter(
+    field: &str,
+    value: Value,
+    filters: &[(&str, DynamicValueKind)],
+) -> Result<PrismaValue, DynamicFilterError> {
+    let entries = match value {
+        Value::Object(entries) => entries.into_iter().collect(),
+        value => vec![("equals".to_string(), value)],
+    };
+
+    entries
+        .into_iter()
+        .map(|(filter, value)| {
+            let kind = filters
+                .iter()
+                .find(|(name, _)| *name == filter)
+                .map(|(_, kind)| *kind)
+                .ok_or_else(|| DynamicFilterError::UnknownFilter {
+                    field: field.to_string(),
+                    filter: filter.clone(),
+                })?;
+
+            Ok((filter, kind.convert(value)))
+        })
+        .collect::<Result<_, _>>()
+        .map(PrismaValue::Object)
+}
+
+/// Converts the JSON filters for a relation field into a `where` value,
+/// checking that each filter is one of `filters` and parsing nested filters with `from_json`.
+pub fn dynamic_relation_filter<T: WhereInput>(
+    field: &str,
+    value: Value,
+    filters: &[&str],
+    from_json: fn(Value) -> Result<Vec<T>, DynamicFilterError>,
+) -> Result<PrismaValue, DynamicFilterError> {
+    let entries = match value {
+        Value::Object(entries) => entries,
+        _ => return Err(DynamicFilterError::ExpectedObject(field.to_string())),
+    };
+
+    entries
+        .into_iter()
+        .map(|(filter, value)| {
+            if !filters.contains(&filter.as_str()) {
+                return Err(DynamicFilterError::UnknownFilter {
+                    field: field.to_string(),
+                    filter,
+                });
+            }
+
+            let value = match value {
+                Value::Null => PrismaValue::Null,
+                value => PrismaValue::Object(merge_fields(
+                    from_json(value)?
+                        .into_iter()
+                        .map(WhereInput::serialize)
+                        .map(Into::into)
+                        .collect(),
+                )),
+            };
+
+            Ok((filter, value))
+        })
+        .collect::<Result<_, _>>()
+        .map(PrismaValue::Object)
+}
+
+/// Parses the value of an `AND`, `OR` or `NOT` operator,
+/// which can be either a single object of filters or a list of them.
+pub fn dynamic_filter_groups<T>(
+    operator: &str,
+    value: Value,
+    from_json: fn(Value) -> Result<Vec<T>, DynamicFilterError>,
+) -> Result<Vec<Vec<T>>, DynamicFilterError> {
+    match value {
+        Value::Array(values) => values.into_iter().map(from_json).collect(),
+        value @ Value::Object(_) => Ok(vec![from_json(value)?]),
+        _ => Err(DynamicFilterError::ExpectedObject(operator.to_string())),
+    }
+}
@@ -0,0 +1,36 @@
+//! Client-side generation of IDs in the same formats as Prisma's default functions,
+//! for when an ID is needed before a record is created.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Generates an ID in the same format as `@default(uuid())`.
+pub fn uuid() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Generates an ID in the same format as `@default(uuid(7))`.
+pub fn uuid_v7() -> String {
+    // uuid only provides v7 behind its unstable flag, so the timestamp is written over a
+    // random v4 UUID, whose variant bits are already the same as v7's
+    let mut bytes = *uuid::Uuid::new_v4().as_bytes();
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default();
+
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x70;
+
+    uuid::Uuid::from_bytes(bytes).to_string()
+}
+
+/// Generates an ID in the same format as `@default(cuid())`.
+pub fn cuid() -> String {
+    cuid::cuid1().expect("Failed to generate cuid")
+}
+
+/// Generates an ID in the same format as `@default(cuid(2))`.
+pub fn cuid2() -> String {
+    cuid::cuid2()
+}
@@ -1,6 +1,8 @@
 pub mod actions;
 mod client;
+mod dynamic_filter;
 mod gen_macros;
+pub mod ids;
 #[cfg(feature = "migrations")]
 pub mod migrations;
 #[cfg(feature = "mocking")]
@@ -29,6 +31,7 @@ pub use user_facing_errors as prisma_errors;
 
 pub use actions::*;
 pub use client::*;
+pub use dynamic_filter::*;
 #[cfg(feature = "mocking")]
 pub use mock::*;
 pub use operator::Operator;
@@ -88,7 +88,18 @@ impl From<prisma_models::PrismaValue> for PrismaValue {
             }
             prisma_models::PrismaValue::Null => Self::Null,
             prisma_models::PrismaValue::DateTime(value) => Self::DateTime(value),
-            prisma_models::PrismaValue::Float(value) => Self::Float(value.to_f64().unwrap()),
+            prisma_models::PrismaValue::Float(value) => {
+                // Float and Decimal values can't be told apart here, but Float values are created by the
+                // engine with BigDecimal::from_f64, so values that don't survive the same conversion
+                // must be Decimals and are kept as strings to be deserialized without losing precision
+                match value
+                    .to_f64()
+                    .filter(|float| BigDecimal::from_f64(*float).as_ref() == Some(&value))
+                {
+                    Some(float) => Self::Float(float),
+                    None => Self::String(value.normalized().to_string()),
+                }
+            }
             prisma_models::PrismaValue::BigInt(value) => Self::BigInt(value),
             prisma_models::PrismaValue::Bytes(value) => Self::Bytes(value),
         }
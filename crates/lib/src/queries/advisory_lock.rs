@@ -0,0 +1,62 @@
+use serde::Deserialize;
+
+use crate::{raw, ExecuteRaw, PrismaClientInternals, PrismaValue, QueryError, QueryRaw};
+
+/// Transaction-level locks are released as soon as the query outside of a transaction finishes,
+/// so acquiring one would have no effect
+fn check_in_transaction(client: &PrismaClientInternals) -> super::Result<()> {
+    match client.in_transaction() {
+        true => Ok(()),
+        false => Err(QueryError::Unsupported(
+            "acquiring an advisory lock outside of a transaction".to_string(),
+        )),
+    }
+}
+
+/// Waits to acquire a PostgreSQL transaction-level advisory lock on `key`.
+/// The lock is released when the transaction ends,
+/// so `client` must be the client of an interactive transaction.
+pub async fn advisory_lock(
+    client: &PrismaClientInternals,
+    key: i64,
+    database: &'static str,
+) -> super::Result<()> {
+    check_in_transaction(client)?;
+
+    ExecuteRaw::new(
+        client,
+        raw!("SELECT pg_advisory_xact_lock({})", PrismaValue::Int(key)),
+        database,
+    )
+    .exec()
+    .await
+    .map(|_| ())
+}
+
+#[derive(Deserialize)]
+struct TryLockResult {
+    locked: bool,
+}
+
+/// Tries to acquire a PostgreSQL transaction-level advisory lock on `key` without waiting,
+/// returning whether the lock was acquired.
+pub async fn try_advisory_lock(
+    client: &PrismaClientInternals,
+    key: i64,
+    database: &'static str,
+) -> super::Result<bool> {
+    check_in_transaction(client)?;
+
+    let rows = QueryRaw::<TryLockResult>::new(
+        client,
+        raw!(
+            "SELECT pg_try_advisory_xact_lock({}) AS locked",
+            PrismaValue::Int(key)
+        ),
+        database,
+    )
+    .exec()
+    .await?;
+
+    Ok(rows.into_iter().any(|row| row.locked))
+}
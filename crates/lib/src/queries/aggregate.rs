@@ -0,0 +1,291 @@
+use std::collections::BTreeMap;
+
+use prisma_models::PrismaValue;
+use query_core::{Operation, Selection};
+
+use crate::{
+    merge_fields, ModelOperation, ModelQuery, ModelReadOperation, ModelTypes, OrderByQuery,
+    PaginatedQuery, PrismaClientInternals, Query, QueryConvert, SerializedWhereInput, WhereInput,
+    WhereQuery,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AggregateOperation {
+    Count,
+    Avg,
+    Sum,
+    Min,
+    Max,
+}
+
+impl AggregateOperation {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Count => "_count",
+            Self::Avg => "_avg",
+            Self::Sum => "_sum",
+            Self::Min => "_min",
+            Self::Max => "_max",
+        }
+    }
+}
+
+/// A filter on the result of an aggregate operation, used in `having` filters.
+#[derive(Clone, Debug)]
+pub enum AggregateFilter<T> {
+    Equals(T),
+    Not(T),
+    Lt(T),
+    Lte(T),
+    Gt(T),
+    Gte(T),
+    InVec(Vec<T>),
+    NotInVec(Vec<T>),
+}
+
+impl<T> AggregateFilter<T> {
+    pub fn serialize(self, to_value: impl Fn(T) -> PrismaValue) -> (String, PrismaValue) {
+        let (action, value) = match self {
+            Self::Equals(value) => ("equals", to_value(value)),
+            Self::Not(value) => ("not", to_value(value)),
+            Self::Lt(value) => ("lt", to_value(value)),
+            Self::Lte(value) => ("lte", to_value(value)),
+            Self::Gt(value) => ("gt", to_value(value)),
+            Self::Gte(value) => ("gte", to_value(value)),
+            Self::InVec(values) => (
+                "in",
+                PrismaValue::List(values.into_iter().map(to_value).collect()),
+            ),
+            Self::NotInVec(values) => (
+                "notIn",
+                PrismaValue::List(values.into_iter().map(to_value).collect()),
+            ),
+        };
+
+        (action.to_string(), value)
+    }
+}
+
+/// Groups aggregate params by their operation, producing selections of the form
+/// `_avg { views } _count { _all views }`.
+pub fn aggregate_selections<T: Into<(AggregateOperation, String)>>(
+    params: Vec<T>,
+) -> Vec<Selection> {
+    let mut grouped = BTreeMap::<AggregateOperation, Vec<String>>::new();
+
+    for param in params {
+        let (op, field) = param.into();
+        let fields = grouped.entry(op).or_default();
+
+        if !fields.contains(&field) {
+            fields.push(field);
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|(op, fields)| {
+            Selection::new(
+                op.name(),
+                None,
+                [],
+                fields
+                    .into_iter()
+                    .map(|field| Selection::new(field, None, [], []))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
+
+pub struct Aggregate<'a, Actions: ModelTypes> {
+    client: &'a PrismaClientInternals,
+    pub where_params: Vec<Actions::Where>,
+    pub order_by_params: Vec<Actions::OrderBy>,
+    pub cursor_params: Vec<Actions::Cursor>,
+    pub aggregate_params: Vec<Actions::Aggregate>,
+    pub skip: Option<i64>,
+    pub take: Option<i64>,
+}
+
+impl<'a, Actions: ModelTypes> Clone for Aggregate<'a, Actions>
+where
+    Actions::Where: Clone,
+    Actions::OrderBy: Clone,
+    Actions::Cursor: Clone,
+    Actions::Aggregate: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            where_params: self.where_params.clone(),
+            order_by_params: self.order_by_params.clone(),
+            cursor_params: self.cursor_params.clone(),
+            aggregate_params: self.aggregate_params.clone(),
+            skip: self.skip,
+            take: self.take,
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for Aggregate<'a, Actions>
+where
+    Actions::Where: std::fmt::Debug,
+    Actions::OrderBy: std::fmt::Debug,
+    Actions::Cursor: std::fmt::Debug,
+    Actions::Aggregate: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Aggregate")
+            .field("model", &Actions::MODEL)
+            .field("where_params", &self.where_params)
+            .field("order_by_params", &self.order_by_params)
+            .field("cursor_params", &self.cursor_params)
+            .field("aggregate_params", &self.aggregate_params)
+            .field("skip", &self.skip)
+            .field("take", &self.take)
+            .finish()
+    }
+}
+
+impl<'a, Actions: ModelTypes> Aggregate<'a, Actions> {
+    pub fn new(client: &'a PrismaClientInternals, where_params: Vec<Actions::Where>) -> Self {
+        Self {
+            client,
+            where_params,
+            order_by_params: vec![],
+            cursor_params: vec![],
+            aggregate_params: vec![],
+            skip: None,
+            take: None,
+        }
+    }
+
+    pub fn with(mut self, param: impl Into<Actions::Aggregate>) -> Self {
+        self.aggregate_params.push(param.into());
+        self
+    }
+
+    pub fn order_by(mut self, param: Actions::OrderBy) -> Self {
+        self.order_by_params.push(param);
+        self
+    }
+
+    pub fn cursor(mut self, param: Actions::Cursor) -> Self {
+        self.cursor_params.push(param);
+        self
+    }
+
+    pub fn skip(mut self, skip: i64) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+
+    pub fn take(mut self, take: i64) -> Self {
+        self.take = Some(take);
+        self
+    }
+
+    pub async fn exec(self) -> super::Result<Actions::AggregateData> {
+        super::exec(self).await
+    }
+}
+
+impl<'a, Actions: ModelTypes> QueryConvert for Aggregate<'a, Actions> {
+    type RawType = Actions::AggregateData;
+    type ReturnValue = Self::RawType;
+
+    fn convert(raw: Self::RawType) -> super::Result<Self::ReturnValue> {
+        Ok(raw)
+    }
+}
+
+impl<'a, Actions: ModelTypes> Query<'a> for Aggregate<'a, Actions> {
+    fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
+        (
+            Operation::Read(Self::base_selection(
+                [
+                    (!self.where_params.is_empty()).then(|| {
+                        (
+                            "where".to_string(),
+                            PrismaValue::Object(merge_fields(
+                                self.where_params
+                                    .into_iter()
+                                    .map(WhereInput::serialize)
+                                    .map(|s| (s.field, s.value.into()))
+                                    .collect(),
+                            ))
+                            .into(),
+                        )
+                    }),
+                    (!self.order_by_params.is_empty()).then(|| {
+                        (
+                            "orderBy".to_string(),
+                            PrismaValue::List(
+                                self.order_by_params
+                                    .into_iter()
+                                    .map(|p| PrismaValue::Object(vec![p.into()]))
+                                    .collect(),
+                            )
+                            .into(),
+                        )
+                    }),
+                    (!self.cursor_params.is_empty()).then(|| {
+                        (
+                            "cursor".to_string(),
+                            PrismaValue::Object(
+                                self.cursor_params
+                                    .into_iter()
+                                    .map(Into::into)
+                                    .map(WhereInput::serialize)
+                                    .map(SerializedWhereInput::transform_equals)
+                                    .collect(),
+                            )
+                            .into(),
+                        )
+                    }),
+                    self.skip
+                        .map(|skip| ("skip".to_string(), PrismaValue::Int(skip as i64).into())),
+                    self.take
+                        .map(|take| ("take".to_string(), PrismaValue::Int(take as i64).into())),
+                ]
+                .into_iter()
+                .flatten(),
+                aggregate_selections(self.aggregate_params),
+            )),
+            self.client,
+        )
+    }
+}
+
+impl<'a, Actions: ModelTypes> ModelQuery<'a> for Aggregate<'a, Actions> {
+    type Types = Actions;
+
+    const TYPE: ModelOperation = ModelOperation::Read(ModelReadOperation::Aggregate);
+}
+
+impl<'a, Actions: ModelTypes> WhereQuery<'a> for Aggregate<'a, Actions> {
+    fn add_where(&mut self, param: Actions::Where) {
+        self.where_params.push(param);
+    }
+}
+
+impl<'a, Actions: ModelTypes> OrderByQuery<'a> for Aggregate<'a, Actions> {
+    fn add_order_by(&mut self, param: Actions::OrderBy) {
+        self.order_by_params.push(param);
+    }
+}
+
+impl<'a, Actions: ModelTypes> PaginatedQuery<'a> for Aggregate<'a, Actions> {
+    fn add_cursor(&mut self, param: Actions::Cursor) {
+        self.cursor_params.push(param);
+    }
+
+    fn set_skip(&mut self, skip: i64) {
+        self.skip = Some(skip);
+    }
+
+    fn set_take(&mut self, take: i64) {
+        self.take = Some(take);
+    }
+}
@@ -1,17 +1,14 @@
-use std::{collections::VecDeque, num::NonZeroUsize};
+use std::collections::VecDeque;
 
-use query_core::Operation;
+use query_core::{BatchDocumentTransaction, Operation};
 
 use crate::{PrismaClientInternals, Query, QueryConvert};
 
-pub enum VecMeta {
-    Empty,
-    NotEmpty(NonZeroUsize, Box<BatchItemDataMeta>),
-}
-
 pub enum BatchItemDataMeta {
     Query,
-    Vec(VecMeta),
+    /// Each item's meta is stored separately since items of a `Vec`
+    /// can have different shapes, eg. nested `Vec`s of different lengths.
+    Vec(Vec<Self>),
     Tuple(Vec<Self>),
 }
 
@@ -25,11 +22,7 @@ impl BatchItemData {
     fn meta(&self) -> BatchItemDataMeta {
         match self {
             Self::Query(_) => BatchItemDataMeta::Query,
-            Self::Vec(v) => BatchItemDataMeta::Vec({
-                NonZeroUsize::new(v.len())
-                    .map(|size| VecMeta::NotEmpty(size, Box::new(v[0].meta())))
-                    .unwrap_or(VecMeta::Empty)
-            }),
+            Self::Vec(v) => BatchItemDataMeta::Vec(v.iter().map(BatchItemData::meta).collect()),
             Self::Tuple(v) => BatchItemDataMeta::Tuple(v.iter().map(BatchItemData::meta).collect()),
         }
     }
@@ -44,7 +37,7 @@ impl BatchItemData {
 }
 
 pub enum BatchDataMeta {
-    Iterator(VecMeta),
+    Iterator(Vec<BatchItemDataMeta>),
     Tuple(Vec<BatchItemDataMeta>),
 }
 
@@ -56,11 +49,9 @@ pub enum BatchData {
 impl BatchData {
     fn meta(&self) -> BatchDataMeta {
         match self {
-            Self::Iterator(v) => BatchDataMeta::Iterator(
-                NonZeroUsize::new(v.len())
-                    .map(|size| VecMeta::NotEmpty(size, Box::new(v[0].meta())))
-                    .unwrap_or(VecMeta::Empty),
-            ),
+            Self::Iterator(v) => {
+                BatchDataMeta::Iterator(v.iter().map(BatchItemData::meta).collect())
+            }
             Self::Tuple(v) => BatchDataMeta::Tuple(v.iter().map(BatchItemData::meta).collect()),
         }
     }
@@ -82,6 +73,16 @@ impl BatchData {
 pub async fn batch<'batch, 'b, T: BatchContainer<'batch, Marker>, Marker>(
     container: T,
     client: &'b PrismaClientInternals,
+) -> super::Result<<T as BatchContainer<'batch, Marker>>::ReturnType> {
+    batch_with_isolation_level(container, client, None).await
+}
+
+/// Executes a batch in a transaction with the given isolation level,
+/// or the database's default isolation level if it is `None`.
+pub async fn batch_with_isolation_level<'batch, 'b, T: BatchContainer<'batch, Marker>, Marker>(
+    container: T,
+    client: &'b PrismaClientInternals,
+    isolation_level: Option<String>,
 ) -> super::Result<<T as BatchContainer<'batch, Marker>>::ReturnType> {
     let data = container.data();
     let meta = data.meta();
@@ -89,8 +90,10 @@ pub async fn batch<'batch, 'b, T: BatchContainer<'batch, Marker>, Marker>(
     let operations = data.operations();
 
     let values = client
-        .engine
-        .execute_all(operations)
+        .execute_all(
+            operations,
+            Some(BatchDocumentTransaction::new(isolation_level)),
+        )
         .await?
         .into_iter()
         .collect::<super::Result<VecDeque<_>>>()?;
@@ -98,6 +101,29 @@ pub async fn batch<'batch, 'b, T: BatchContainer<'batch, Marker>, Marker>(
     T::resolve(meta, values)
 }
 
+/// Sends multiple queries to the query engine at once without wrapping them in a transaction,
+/// returning the result of each query in the order they were provided.
+/// The queries may be executed concurrently, and a failed query doesn't affect the others.
+pub async fn batch_without_transaction<'a, Q: Query<'a>>(
+    queries: impl IntoIterator<Item = Q>,
+    client: &PrismaClientInternals,
+) -> super::Result<Vec<super::Result<Q::ReturnValue>>> {
+    let operations = queries.into_iter().map(|q| q.graphql().0).collect();
+
+    Ok(client
+        .execute_all(operations, None)
+        .await?
+        .into_iter()
+        .map(|value| {
+            Q::convert(
+                value?
+                    .deserialize_into::<Q::RawType>()
+                    .map_err(|e| super::QueryError::Deserialize(e.to_string()))?,
+            )
+        })
+        .collect())
+}
+
 pub trait BatchItemParent {
     type ReturnValue;
 }
@@ -117,19 +143,29 @@ impl<Q: QueryConvert> BatchItemParent for Q {
 
 impl<'a, 'b, Q: Query<'a>> BatchItem<'b> for Q {
     fn data(self) -> BatchItemData {
-        BatchItemData::Query(self.graphql().0)
+        let mut operations = self.batch_operations();
+
+        match operations.len() {
+            1 => BatchItemData::Query(operations.remove(0)),
+            _ => BatchItemData::Vec(operations.into_iter().map(BatchItemData::Query).collect()),
+        }
     }
 
     fn resolve(
-        _: &BatchItemDataMeta,
+        meta: &BatchItemDataMeta,
         values: &mut VecDeque<serde_value::Value>,
     ) -> super::Result<<Self as BatchItemParent>::ReturnValue> {
-        Q::convert(
+        // queries that were split up have the meta of each of their operations
+        let operations = match meta {
+            BatchItemDataMeta::Vec(metas) => metas.len(),
+            _ => 1,
+        };
+
+        Q::batch_convert(
             values
-                .pop_front()
-                .unwrap()
-                .deserialize_into::<Q::RawType>()
-                .unwrap(),
+                .drain(..operations)
+                .map(|value| value.deserialize_into::<Q::RawType>().unwrap())
+                .collect(),
         )
     }
 }
@@ -138,7 +174,7 @@ impl<'batch, I: BatchItemParent> BatchItemParent for Vec<I> {
     type ReturnValue = Vec<<I as BatchItemParent>::ReturnValue>;
 }
 
-impl<'batch, 'query, I: Query<'query>> BatchItem<'batch> for Vec<I> {
+impl<'batch, 'item: 'batch, I: BatchItem<'item>> BatchItem<'batch> for Vec<I> {
     fn data(self) -> BatchItemData {
         BatchItemData::Vec(self.into_iter().map(BatchItem::data).collect())
     }
@@ -148,12 +184,10 @@ impl<'batch, 'query, I: Query<'query>> BatchItem<'batch> for Vec<I> {
         values: &mut VecDeque<serde_value::Value>,
     ) -> super::Result<<Self as BatchItemParent>::ReturnValue> {
         Ok(match meta {
-            BatchItemDataMeta::Vec(meta) => match meta {
-                VecMeta::Empty => vec![],
-                VecMeta::NotEmpty(size, meta) => (0..size.get())
-                    .map(|_| <I as BatchItem>::resolve(meta.as_ref(), values))
-                    .collect::<super::Result<Vec<_>>>()?,
-            },
+            BatchItemDataMeta::Vec(metas) => metas
+                .iter()
+                .map(|meta| <I as BatchItem>::resolve(meta, values))
+                .collect::<super::Result<Vec<_>>>()?,
             _ => unreachable!(),
         })
     }
@@ -185,12 +219,10 @@ impl<'batch, 't: 'batch, T: BatchItem<'t>, I: IntoIterator<Item = T>> BatchConta
         mut values: VecDeque<serde_value::Value>,
     ) -> super::Result<Self::ReturnType> {
         Ok(match meta {
-            BatchDataMeta::Iterator(meta) => match meta {
-                VecMeta::Empty => vec![],
-                VecMeta::NotEmpty(size, meta) => (0..size.get())
-                    .map(|_| T::resolve(&meta, &mut values))
-                    .collect::<super::Result<Vec<_>>>()?,
-            },
+            BatchDataMeta::Iterator(metas) => metas
+                .iter()
+                .map(|meta| T::resolve(meta, &mut values))
+                .collect::<super::Result<Vec<_>>>()?,
             _ => unreachable!(),
         })
     }
@@ -0,0 +1,45 @@
+use crate::{ExecuteRaw, PrismaClientInternals, Raw};
+
+async fn set_constraints(
+    client: &PrismaClientInternals,
+    constraints: &[&str],
+    mode: &str,
+) -> super::Result<()> {
+    // constraint names are identifiers, so they can't be passed as parameters
+    let constraints = if constraints.is_empty() {
+        "ALL".to_string()
+    } else {
+        constraints
+            .iter()
+            .map(|name| format!("\"{}\"", name.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    ExecuteRaw::new(
+        client,
+        Raw::new(&format!("SET CONSTRAINTS {constraints} {mode}"), vec![]),
+        "postgresql",
+    )
+    .exec()
+    .await
+    .map(|_| ())
+}
+
+/// Defers checking the given deferrable constraints, or all of them if `constraints` is empty,
+/// until the current transaction is committed.
+pub async fn defer_constraints(
+    client: &PrismaClientInternals,
+    constraints: &[&str],
+) -> super::Result<()> {
+    set_constraints(client, constraints, "DEFERRED").await
+}
+
+/// Checks the given deferrable constraints, or all of them if `constraints` is empty,
+/// immediately and after each following query in the current transaction.
+pub async fn check_constraints_immediately(
+    client: &PrismaClientInternals,
+    constraints: &[&str],
+) -> super::Result<()> {
+    set_constraints(client, constraints, "IMMEDIATE").await
+}
@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
+
 use prisma_models::PrismaValue;
-use query_core::{Operation, Selection};
-use serde::Deserialize;
+use query_core::{Operation, Selection, SelectionArgument};
+use serde::{de::Error, Deserialize, Deserializer};
 
 use crate::{
     merge_fields, ModelOperation, ModelQuery, ModelReadOperation, ModelTypes, OrderByQuery,
@@ -15,6 +17,50 @@ pub struct Count<'a, Actions: ModelTypes> {
     pub cursor_params: Vec<Actions::Cursor>,
     pub skip: Option<i64>,
     pub take: Option<i64>,
+    pub distinct: Vec<Actions::ScalarField>,
+    pub field: Option<Actions::ScalarField>,
+}
+
+impl<'a, Actions: ModelTypes> Clone for Count<'a, Actions>
+where
+    Actions::Where: Clone,
+    Actions::OrderBy: Clone,
+    Actions::Cursor: Clone,
+    Actions::ScalarField: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            where_params: self.where_params.clone(),
+            order_by_params: self.order_by_params.clone(),
+            cursor_params: self.cursor_params.clone(),
+            skip: self.skip,
+            take: self.take,
+            distinct: self.distinct.clone(),
+            field: self.field.clone(),
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for Count<'a, Actions>
+where
+    Actions::Where: std::fmt::Debug,
+    Actions::OrderBy: std::fmt::Debug,
+    Actions::Cursor: std::fmt::Debug,
+    Actions::ScalarField: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Count")
+            .field("model", &Actions::MODEL)
+            .field("where_params", &self.where_params)
+            .field("order_by_params", &self.order_by_params)
+            .field("cursor_params", &self.cursor_params)
+            .field("skip", &self.skip)
+            .field("take", &self.take)
+            .field("distinct", &self.distinct)
+            .field("field", &self.field)
+            .finish()
+    }
 }
 
 impl<'a, Actions: ModelTypes> Count<'a, Actions> {
@@ -26,6 +72,8 @@ impl<'a, Actions: ModelTypes> Count<'a, Actions> {
             cursor_params: vec![],
             skip: None,
             take: None,
+            distinct: vec![],
+            field: None,
         }
     }
 
@@ -49,6 +97,21 @@ impl<'a, Actions: ModelTypes> Count<'a, Actions> {
         self
     }
 
+    /// Counts the distinct combinations of values of the provided fields,
+    /// rather than the number of records.
+    /// `order_by`, `skip` and `take` apply to the combinations rather than to records,
+    /// and `cursor` isn't supported by the engine for distinct counts.
+    pub fn distinct(mut self, fields: Vec<Actions::ScalarField>) -> Self {
+        self.distinct.extend(fields);
+        self
+    }
+
+    /// Only counts records where the provided field is not null.
+    pub fn field(mut self, field: Actions::ScalarField) -> Self {
+        self.field = Some(field);
+        self
+    }
+
     pub async fn exec(self) -> super::Result<i64> {
         super::exec(self).await
     }
@@ -59,77 +122,151 @@ pub struct CountAggregateResult {
     _count: CountResult,
 }
 
+/// The count is keyed by the counted field, which is `_all` unless `field` is used
 #[derive(Deserialize)]
+#[serde(from = "BTreeMap<String, i64>")]
 pub struct CountResult {
     _all: i64,
 }
 
+impl From<BTreeMap<String, i64>> for CountResult {
+    fn from(count: BTreeMap<String, i64>) -> Self {
+        Self {
+            _all: count.into_values().next().unwrap_or(0),
+        }
+    }
+}
+
+/// A distinct count is executed as a groupBy, which returns a list of groups
+/// rather than a single aggregate
+#[doc(hidden)]
+pub enum CountResponse {
+    Aggregate(CountAggregateResult),
+    GroupBy(Vec<CountAggregateResult>),
+}
+
+impl<'de> Deserialize<'de> for CountResponse {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match serde_value::Value::deserialize(deserializer)? {
+            value @ serde_value::Value::Seq(_) => value.deserialize_into().map(Self::GroupBy),
+            value => value.deserialize_into().map(Self::Aggregate),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
 impl<'a, Actions: ModelTypes> QueryConvert for Count<'a, Actions> {
-    type RawType = CountAggregateResult;
+    type RawType = CountResponse;
     type ReturnValue = i64;
 
     fn convert(raw: Self::RawType) -> super::Result<Self::ReturnValue> {
-        Ok(raw._count._all)
+        Ok(match raw {
+            CountResponse::Aggregate(result) => result._count._all,
+            // each group is a distinct combination of values,
+            // but groups where the counted field is null have a count of 0
+            CountResponse::GroupBy(groups) => {
+                groups.iter().filter(|group| group._count._all > 0).count() as i64
+            }
+        })
     }
 }
 
 impl<'a, Actions: ModelTypes> Query<'a> for Count<'a, Actions> {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
-        (
-            Operation::Read(Self::base_selection(
-                [
-                    (!self.where_params.is_empty()).then(|| {
-                        (
-                            "where".to_string(),
-                            PrismaValue::Object(merge_fields(
-                                self.where_params
-                                    .into_iter()
-                                    .map(WhereInput::serialize)
-                                    .map(|s| (s.field, s.value.into()))
-                                    .collect(),
-                            ))
-                            .into(),
-                        )
-                    }),
-                    (!self.order_by_params.is_empty()).then(|| {
-                        (
-                            "orderBy".to_string(),
+        let where_arg: Option<SelectionArgument> = (!self.where_params.is_empty()).then(|| {
+            (
+                "where".to_string(),
+                PrismaValue::Object(merge_fields(
+                    self.where_params
+                        .into_iter()
+                        .map(WhereInput::serialize)
+                        .map(|s| (s.field, s.value.into()))
+                        .collect(),
+                ))
+                .into(),
+            )
+        });
+
+        let count_selection = Selection::new(
+            "_count",
+            None,
+            [],
+            [Selection::new(
+                self.field
+                    .map(|field| field.to_string())
+                    .unwrap_or_else(|| "_all".to_string()),
+                None,
+                [],
+                [],
+            )],
+        );
+
+        let pagination_args = [
+            (!self.order_by_params.is_empty()).then(|| {
+                (
+                    "orderBy".to_string(),
+                    PrismaValue::List(
+                        self.order_by_params
+                            .into_iter()
+                            .map(|p| PrismaValue::Object(vec![p.into()]))
+                            .collect(),
+                    )
+                    .into(),
+                )
+            }),
+            (!self.cursor_params.is_empty()).then(|| {
+                (
+                    "cursor".to_string(),
+                    PrismaValue::Object(
+                        self.cursor_params
+                            .into_iter()
+                            .map(Into::into)
+                            .map(WhereInput::serialize)
+                            .map(SerializedWhereInput::transform_equals)
+                            .collect(),
+                    )
+                    .into(),
+                )
+            }),
+            self.skip
+                .map(|skip| ("skip".to_string(), PrismaValue::Int(skip as i64).into())),
+            self.take
+                .map(|take| ("take".to_string(), PrismaValue::Int(take as i64).into())),
+        ];
+
+        // The engine has no distinct count, so distinct values are found by grouping on them
+        if !self.distinct.is_empty() {
+            return (
+                Operation::Read(Selection::new(
+                    format!("{}{}", ModelReadOperation::GroupBy.name(), Actions::MODEL),
+                    None,
+                    [
+                        Some((
+                            "by".to_string(),
                             PrismaValue::List(
-                                self.order_by_params
-                                    .into_iter()
-                                    .map(|p| PrismaValue::Object(vec![p.into()]))
-                                    .collect(),
-                            )
-                            .into(),
-                        )
-                    }),
-                    (!self.cursor_params.is_empty()).then(|| {
-                        (
-                            "cursor".to_string(),
-                            PrismaValue::Object(
-                                self.cursor_params
+                                self.distinct
                                     .into_iter()
-                                    .map(Into::into)
-                                    .map(WhereInput::serialize)
-                                    .map(SerializedWhereInput::transform_equals)
+                                    .map(|field| PrismaValue::Enum(field.to_string()))
                                     .collect(),
                             )
                             .into(),
-                        )
-                    }),
-                    self.skip
-                        .map(|skip| ("skip".to_string(), PrismaValue::Int(skip as i64).into())),
-                    self.take
-                        .map(|take| ("take".to_string(), PrismaValue::Int(take as i64).into())),
-                ]
-                .into_iter()
-                .flatten(),
-                [Selection::new(
-                    "_count",
-                    None,
-                    [],
-                    [Selection::new("_all", None, [], [])],
-                )],
+                        )),
+                        where_arg,
+                    ]
+                    .into_iter()
+                    .chain(pagination_args)
+                    .flatten()
+                    .collect::<Vec<_>>(),
+                    vec![count_selection],
+                )),
+                self.client,
+            );
+        }
+
+        (
+            Operation::Read(Self::base_selection(
+                [where_arg].into_iter().chain(pagination_args).flatten(),
+                [count_selection],
             )),
             self.client,
         )
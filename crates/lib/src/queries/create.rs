@@ -82,7 +82,7 @@ impl<'a, Actions: ModelTypes> Query<'a> for Create<'a, Actions> {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
         let mut scalar_selections = Actions::scalar_selections();
 
-        scalar_selections.extend(self.with_params.into_iter().map(Into::into));
+        scalar_selections.extend(Actions::with_selections(self.with_params));
 
         (
             Operation::Write(Self::to_selection(self.set_params, scalar_selections)),
@@ -10,6 +10,7 @@ pub struct CreateMany<'a, Actions: ModelTypes> {
     client: &'a PrismaClientInternals,
     pub set_params: Vec<Vec<Actions::UncheckedSet>>,
     pub skip_duplicates: bool,
+    pub chunk_size: Option<usize>,
 }
 
 impl<'a, Actions: ModelTypes> CreateMany<'a, Actions> {
@@ -21,6 +22,7 @@ impl<'a, Actions: ModelTypes> CreateMany<'a, Actions> {
             client,
             set_params,
             skip_duplicates: false,
+            chunk_size: None,
         }
     }
 
@@ -30,6 +32,50 @@ impl<'a, Actions: ModelTypes> CreateMany<'a, Actions> {
         self
     }
 
+    /// Splits the records into queries of at most `chunk_size` records.
+    /// Queries are still split further if they would exceed the database's bind parameter limit.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = Some(chunk_size.max(1));
+        self
+    }
+
+    /// How many records each query can create, which is the smaller of `chunk_size` and
+    /// how many fit in the database's bind parameter limit, assuming that every column of a record is bound
+    fn records_per_query(&self) -> Option<usize> {
+        let bind_limit = max_bind_values(self.client.url()).map(|max_bind_values| {
+            (max_bind_values / Actions::scalar_selections().len().max(1)).max(1)
+        });
+
+        match (self.chunk_size, bind_limit) {
+            (Some(chunk_size), Some(bind_limit)) => Some(chunk_size.min(bind_limit)),
+            (chunk_size, bind_limit) => chunk_size.or(bind_limit),
+        }
+    }
+
+    fn chunks(self, chunk_size: usize) -> Vec<Self> {
+        let Self {
+            client,
+            mut set_params,
+            skip_duplicates,
+            ..
+        } = self;
+
+        let mut chunks = vec![];
+
+        while !set_params.is_empty() {
+            let rest = set_params.split_off(chunk_size.max(1).min(set_params.len()));
+
+            chunks.push(Self {
+                client,
+                set_params: std::mem::replace(&mut set_params, rest),
+                skip_duplicates,
+                chunk_size: None,
+            });
+        }
+
+        chunks
+    }
+
     fn to_selection(
         set_params: Vec<Vec<Actions::UncheckedSet>>,
         _skip_duplicates: bool,
@@ -64,8 +110,50 @@ impl<'a, Actions: ModelTypes> CreateMany<'a, Actions> {
         )
     }
 
+    /// Creates the records, splitting them into multiple queries if there are more than `chunk_size`
+    /// or too many for the database's bind parameter limit.
+    /// The queries are executed in a single transaction, so either all of the records are created or none are.
     pub async fn exec(self) -> super::Result<i64> {
-        super::exec(self).await
+        match self.records_per_query() {
+            Some(records_per_query) if self.set_params.len() > records_per_query => {
+                let client = self.client;
+
+                super::batch(self, client).await
+            }
+            _ => super::exec(self).await,
+        }
+    }
+
+    /// Creates the records using separate queries of at most `chunk_size` records,
+    /// returning the number of records created by each query.
+    /// The queries are not executed in a transaction,
+    /// so an error may occur after some of the records have been created.
+    pub async fn exec_in_batches(self, chunk_size: usize) -> super::Result<Vec<i64>> {
+        let query = self.chunk_size(chunk_size);
+        let records_per_query = query.records_per_query().unwrap_or(chunk_size);
+
+        let mut counts = vec![];
+
+        for chunk in query.chunks(records_per_query) {
+            counts.push(super::exec(chunk).await?);
+        }
+
+        Ok(counts)
+    }
+}
+
+/// The maximum number of bind parameters in a query for the database at `url`
+fn max_bind_values(url: &str) -> Option<usize> {
+    if url.starts_with("postgres") {
+        Some(32767)
+    } else if url.starts_with("mysql") {
+        Some(65535)
+    } else if url.starts_with("sqlserver") {
+        Some(2099)
+    } else if url.starts_with("file") {
+        Some(999)
+    } else {
+        None
     }
 }
 
@@ -89,6 +177,21 @@ impl<'a, Actions: ModelTypes> Query<'a> for CreateMany<'a, Actions> {
             self.client,
         )
     }
+
+    fn batch_operations(self) -> Vec<Operation> {
+        match self.records_per_query() {
+            Some(records_per_query) if self.set_params.len() > records_per_query => self
+                .chunks(records_per_query)
+                .into_iter()
+                .map(|chunk| chunk.graphql().0)
+                .collect(),
+            _ => vec![self.graphql().0],
+        }
+    }
+
+    fn batch_convert(raw: Vec<Self::RawType>) -> super::Result<Self::ReturnValue> {
+        Ok(raw.into_iter().map(|result| result.count).sum())
+    }
 }
 
 impl<'a, Actions: ModelTypes> ModelQuery<'a> for CreateMany<'a, Actions> {
@@ -81,7 +81,7 @@ impl<'a, Actions: ModelTypes> Query<'a> for Delete<'a, Actions> {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
         let mut scalar_selections = Actions::scalar_selections();
 
-        scalar_selections.extend(self.with_params.into_iter().map(Into::into));
+        scalar_selections.extend(Actions::with_selections(self.with_params));
 
         (
             Operation::Write(Self::to_selection(self.where_param, scalar_selections)),
@@ -11,6 +11,30 @@ pub struct DeleteMany<'a, Actions: ModelTypes> {
     pub where_params: Vec<Actions::Where>,
 }
 
+impl<'a, Actions: ModelTypes> Clone for DeleteMany<'a, Actions>
+where
+    Actions::Where: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            where_params: self.where_params.clone(),
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for DeleteMany<'a, Actions>
+where
+    Actions::Where: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeleteMany")
+            .field("model", &Actions::MODEL)
+            .field("where_params", &self.where_params)
+            .finish()
+    }
+}
+
 impl<'a, Actions: ModelTypes> DeleteMany<'a, Actions> {
     pub fn new(client: &'a PrismaClientInternals, where_params: Vec<Actions::Where>) -> Self {
         Self {
@@ -19,6 +43,16 @@ impl<'a, Actions: ModelTypes> DeleteMany<'a, Actions> {
         }
     }
 
+    pub fn where_(mut self, param: impl Into<Actions::Where>) -> Self {
+        self.where_params.push(param.into());
+        self
+    }
+
+    pub fn extend_where(mut self, params: impl IntoIterator<Item = Actions::Where>) -> Self {
+        self.where_params.extend(params);
+        self
+    }
+
     pub(crate) fn convert(raw: BatchResult) -> i64 {
         raw.count
     }
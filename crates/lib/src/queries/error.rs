@@ -1,6 +1,6 @@
 use serde::Serialize;
 use thiserror::Error;
-use user_facing_errors::UserFacingError;
+use user_facing_errors::{query_engine::RecordRequiredButNotFound, KnownError, UserFacingError};
 
 #[derive(Debug, Error, Serialize)]
 pub enum QueryError {
@@ -12,6 +12,12 @@ pub enum QueryError {
 
     #[error("Error deserializing query result into return type: {0}")]
     Deserialize(String),
+
+    #[error("Record not updated, its version doesn't match the expected version")]
+    StaleVersion,
+
+    #[error("Unsupported operation: {0}")]
+    Unsupported(String),
 }
 
 impl QueryError {
@@ -24,6 +30,28 @@ impl QueryError {
             _ => false,
         }
     }
+
+    /// Whether a transaction failed due to a write conflict or deadlock (`P2034`),
+    /// in which case it can be retried.
+    pub fn is_write_conflict(&self) -> bool {
+        match self {
+            Self::Execute(error) => error
+                .as_known()
+                .map(|e| e.error_code == "P2034")
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// The error the engine returns for `OrThrow` queries that don't find a record
+    pub(crate) fn record_not_found() -> Self {
+        Self::Execute(
+            KnownError::new(RecordRequiredButNotFound {
+                cause: "Expected a record, found none.".to_string(),
+            })
+            .into(),
+        )
+    }
 }
 
 pub type Result<T> = std::result::Result<T, QueryError>;
@@ -2,9 +2,9 @@ use prisma_models::PrismaValue;
 use query_core::{Operation, Selection};
 
 use crate::{
-    merge_fields, Include, IncludeType, ModelOperation, ModelQuery, ModelReadOperation, ModelTypes,
-    OrderByQuery, PaginatedQuery, PrismaClientInternals, Query, QueryConvert, Select, SelectType,
-    WhereInput, WhereQuery, WithQuery,
+    merge_fields, Count, Include, IncludeType, ModelOperation, ModelQuery, ModelReadOperation,
+    ModelTypes, OrderByQuery, PaginatedQuery, PrismaClientInternals, Query, QueryConvert, Select,
+    SelectType, WhereInput, WhereQuery, WithQuery,
 };
 
 use super::SerializedWhereInput;
@@ -17,6 +17,51 @@ pub struct FindFirst<'a, Actions: ModelTypes> {
     pub cursor_params: Vec<Actions::Cursor>,
     pub skip: Option<i64>,
     pub take: Option<i64>,
+    pub distinct: Vec<Actions::ScalarField>,
+}
+
+impl<'a, Actions: ModelTypes> Clone for FindFirst<'a, Actions>
+where
+    Actions::Where: Clone,
+    Actions::With: Clone,
+    Actions::OrderBy: Clone,
+    Actions::Cursor: Clone,
+    Actions::ScalarField: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            where_params: self.where_params.clone(),
+            with_params: self.with_params.clone(),
+            order_by_params: self.order_by_params.clone(),
+            cursor_params: self.cursor_params.clone(),
+            skip: self.skip,
+            take: self.take,
+            distinct: self.distinct.clone(),
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for FindFirst<'a, Actions>
+where
+    Actions::Where: std::fmt::Debug,
+    Actions::With: std::fmt::Debug,
+    Actions::OrderBy: std::fmt::Debug,
+    Actions::Cursor: std::fmt::Debug,
+    Actions::ScalarField: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FindFirst")
+            .field("model", &Actions::MODEL)
+            .field("where_params", &self.where_params)
+            .field("with_params", &self.with_params)
+            .field("order_by_params", &self.order_by_params)
+            .field("cursor_params", &self.cursor_params)
+            .field("skip", &self.skip)
+            .field("take", &self.take)
+            .field("distinct", &self.distinct)
+            .finish()
+    }
 }
 
 impl<'a, Actions: ModelTypes> FindFirst<'a, Actions> {
@@ -29,9 +74,20 @@ impl<'a, Actions: ModelTypes> FindFirst<'a, Actions> {
             cursor_params: vec![],
             skip: None,
             take: None,
+            distinct: vec![],
         }
     }
 
+    pub fn where_(mut self, param: impl Into<Actions::Where>) -> Self {
+        self.where_params.push(param.into());
+        self
+    }
+
+    pub fn extend_where(mut self, params: impl IntoIterator<Item = Actions::Where>) -> Self {
+        self.where_params.extend(params);
+        self
+    }
+
     pub fn with(mut self, param: impl Into<Actions::With>) -> Self {
         self.with_params.push(param.into());
         self
@@ -57,12 +113,18 @@ impl<'a, Actions: ModelTypes> FindFirst<'a, Actions> {
         self
     }
 
+    pub fn distinct(mut self, fields: Vec<Actions::ScalarField>) -> Self {
+        self.distinct.extend(fields);
+        self
+    }
+
     fn to_selection(
         where_params: Vec<Actions::Where>,
         order_by_params: Vec<Actions::OrderBy>,
         cursor_params: Vec<Actions::Cursor>,
         skip: Option<i64>,
         take: Option<i64>,
+        distinct: Vec<Actions::ScalarField>,
         nested_selections: impl IntoIterator<Item = Selection>,
     ) -> Selection {
         Self::base_selection(
@@ -108,6 +170,18 @@ impl<'a, Actions: ModelTypes> FindFirst<'a, Actions> {
                 }),
                 skip.map(|skip| ("skip".to_string(), PrismaValue::Int(skip as i64).into())),
                 take.map(|take| ("take".to_string(), PrismaValue::Int(take as i64).into())),
+                (!distinct.is_empty()).then(|| {
+                    (
+                        "distinct".to_string(),
+                        PrismaValue::List(
+                            distinct
+                                .into_iter()
+                                .map(|field| PrismaValue::Enum(field.to_string()))
+                                .collect(),
+                        )
+                        .into(),
+                    )
+                }),
             ]
             .into_iter()
             .flatten(),
@@ -127,6 +201,7 @@ impl<'a, Actions: ModelTypes> FindFirst<'a, Actions> {
                 self.cursor_params,
                 self.skip,
                 self.take,
+                self.distinct,
                 select.to_selections(),
             )),
         )
@@ -144,6 +219,7 @@ impl<'a, Actions: ModelTypes> FindFirst<'a, Actions> {
                 self.cursor_params,
                 self.skip,
                 self.take,
+                self.distinct,
                 include.to_selections(),
             )),
         )
@@ -152,6 +228,26 @@ impl<'a, Actions: ModelTypes> FindFirst<'a, Actions> {
     pub async fn exec(self) -> super::Result<Option<Actions::Data>> {
         super::exec(self).await
     }
+
+    /// Like `exec`, but returns a `RecordRequiredButNotFound` (P2025) error if no record is found.
+    pub async fn exec_or_throw(self) -> super::Result<Actions::Data> {
+        self.exec()
+            .await?
+            .ok_or_else(super::QueryError::record_not_found)
+    }
+
+    /// Checks whether a matching record exists by counting at most one record,
+    /// rather than fetching and deserializing it.
+    pub async fn exists(self) -> super::Result<bool> {
+        let mut count = Count::new(self.client, self.where_params);
+
+        count.order_by_params = self.order_by_params;
+        count.cursor_params = self.cursor_params;
+        count.skip = self.skip;
+        count.take = Some(1);
+
+        Ok(count.exec().await? > 0)
+    }
 }
 
 impl<'a, Actions: ModelTypes> QueryConvert for FindFirst<'a, Actions> {
@@ -167,7 +263,7 @@ impl<'a, Actions: ModelTypes> Query<'a> for FindFirst<'a, Actions> {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
         let mut scalar_selections = Actions::scalar_selections();
 
-        scalar_selections.extend(self.with_params.into_iter().map(Into::into));
+        scalar_selections.extend(Actions::with_selections(self.with_params));
 
         (
             Operation::Read(Self::to_selection(
@@ -176,6 +272,7 @@ impl<'a, Actions: ModelTypes> Query<'a> for FindFirst<'a, Actions> {
                 self.cursor_params,
                 self.skip,
                 self.take,
+                self.distinct,
                 scalar_selections,
             )),
             self.client,
@@ -1,10 +1,12 @@
+use futures::{stream, Stream, TryStreamExt};
 use prisma_models::PrismaValue;
 use query_core::{ArgumentValue, Operation, Selection};
+use serde::Serialize;
 
 use crate::{
-    merge_fields, Include, IncludeType, ModelOperation, ModelQuery, ModelReadOperation, ModelTypes,
-    OrderByQuery, PaginatedQuery, PrismaClientInternals, Query, QueryConvert, Select, SelectType,
-    WhereInput, WhereQuery, WithQuery,
+    merge_fields, Count, Include, IncludeType, ModelOperation, ModelQuery, ModelReadOperation,
+    ModelTypes, OrderByQuery, PaginatedQuery, PrismaClientInternals, Query, QueryConvert, Select,
+    SelectType, WhereInput, WhereQuery, WithQuery,
 };
 
 use super::SerializedWhereInput;
@@ -17,6 +19,51 @@ pub struct FindMany<'a, Actions: ModelTypes> {
     pub cursor_params: Vec<Actions::Cursor>,
     pub skip: Option<i64>,
     pub take: Option<i64>,
+    pub distinct: Vec<Actions::ScalarField>,
+}
+
+impl<'a, Actions: ModelTypes> Clone for FindMany<'a, Actions>
+where
+    Actions::Where: Clone,
+    Actions::With: Clone,
+    Actions::OrderBy: Clone,
+    Actions::Cursor: Clone,
+    Actions::ScalarField: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            where_params: self.where_params.clone(),
+            with_params: self.with_params.clone(),
+            order_by_params: self.order_by_params.clone(),
+            cursor_params: self.cursor_params.clone(),
+            skip: self.skip,
+            take: self.take,
+            distinct: self.distinct.clone(),
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for FindMany<'a, Actions>
+where
+    Actions::Where: std::fmt::Debug,
+    Actions::With: std::fmt::Debug,
+    Actions::OrderBy: std::fmt::Debug,
+    Actions::Cursor: std::fmt::Debug,
+    Actions::ScalarField: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FindMany")
+            .field("model", &Actions::MODEL)
+            .field("where_params", &self.where_params)
+            .field("with_params", &self.with_params)
+            .field("order_by_params", &self.order_by_params)
+            .field("cursor_params", &self.cursor_params)
+            .field("skip", &self.skip)
+            .field("take", &self.take)
+            .field("distinct", &self.distinct)
+            .finish()
+    }
 }
 
 impl<'a, Actions: ModelTypes> FindMany<'a, Actions> {
@@ -29,9 +76,20 @@ impl<'a, Actions: ModelTypes> FindMany<'a, Actions> {
             cursor_params: vec![],
             skip: None,
             take: None,
+            distinct: vec![],
         }
     }
 
+    pub fn where_(mut self, param: impl Into<Actions::Where>) -> Self {
+        self.where_params.push(param.into());
+        self
+    }
+
+    pub fn extend_where(mut self, params: impl IntoIterator<Item = Actions::Where>) -> Self {
+        self.where_params.extend(params);
+        self
+    }
+
     pub fn with(mut self, param: impl Into<Actions::With>) -> Self {
         self.with_params.push(param.into());
         self
@@ -57,12 +115,25 @@ impl<'a, Actions: ModelTypes> FindMany<'a, Actions> {
         self
     }
 
+    /// Takes the last `n` records, relative to the cursor if one is provided.
+    /// Records are still returned in the order specified by `order_by`.
+    pub fn last(mut self, n: i64) -> Self {
+        self.take = Some(-n);
+        self
+    }
+
+    pub fn distinct(mut self, fields: Vec<Actions::ScalarField>) -> Self {
+        self.distinct.extend(fields);
+        self
+    }
+
     fn to_selection(
         where_params: Vec<Actions::Where>,
         order_by_params: Vec<Actions::OrderBy>,
         cursor_params: Vec<Actions::Cursor>,
         skip: Option<i64>,
         take: Option<i64>,
+        distinct: Vec<Actions::ScalarField>,
         nested_selections: impl IntoIterator<Item = Selection>,
     ) -> Selection {
         Self::base_selection(
@@ -108,6 +179,18 @@ impl<'a, Actions: ModelTypes> FindMany<'a, Actions> {
                 }),
                 skip.map(|skip| ("skip".to_string(), PrismaValue::Int(skip as i64).into())),
                 take.map(|take| ("take".to_string(), PrismaValue::Int(take as i64).into())),
+                (!distinct.is_empty()).then(|| {
+                    (
+                        "distinct".to_string(),
+                        PrismaValue::List(
+                            distinct
+                                .into_iter()
+                                .map(|field| PrismaValue::Enum(field.to_string()))
+                                .collect(),
+                        )
+                        .into(),
+                    )
+                }),
             ]
             .into_iter()
             .flatten(),
@@ -127,6 +210,7 @@ impl<'a, Actions: ModelTypes> FindMany<'a, Actions> {
                 self.cursor_params,
                 self.skip,
                 self.take,
+                self.distinct,
                 select.to_selections(),
             )),
         )
@@ -144,6 +228,7 @@ impl<'a, Actions: ModelTypes> FindMany<'a, Actions> {
                 self.cursor_params,
                 self.skip,
                 self.take,
+                self.distinct,
                 include.to_selections(),
             )),
         )
@@ -152,6 +237,145 @@ impl<'a, Actions: ModelTypes> FindMany<'a, Actions> {
     pub async fn exec(self) -> super::Result<Vec<Actions::Data>> {
         super::exec(self).await
     }
+
+    /// Fetches the 1-indexed `page` of records along with the total number of matching records,
+    /// executing both queries in a single batch.
+    /// `page` and `per_page` must be at least 1, and the query can't already have a
+    /// `skip`, `take`, `cursor` or `distinct` since the total only counts the query's filters.
+    /// Otherwise [`QueryError::Unsupported`](super::QueryError::Unsupported) is returned.
+    pub async fn paginate(self, page: i64, per_page: i64) -> super::Result<Paginated<Actions::Data>>
+    where
+        Actions::Where: Clone,
+    {
+        if page < 1 || per_page < 1 {
+            return Err(super::QueryError::Unsupported(
+                "paginating with a page or per_page less than 1".to_string(),
+            ));
+        }
+
+        if self.skip.is_some()
+            || self.take.is_some()
+            || !self.cursor_params.is_empty()
+            || !self.distinct.is_empty()
+        {
+            return Err(super::QueryError::Unsupported(
+                "paginating a query with a skip, take, cursor or distinct".to_string(),
+            ));
+        }
+
+        let client = self.client;
+
+        let count = Count::new(client, self.where_params.clone());
+        let find_many = self.skip((page - 1) * per_page).take(per_page);
+
+        let (items, total) = super::batch((find_many, count), client).await?;
+
+        Ok(Paginated {
+            items,
+            total,
+            page,
+            per_page,
+            page_count: (total + per_page - 1) / per_page,
+        })
+    }
+
+    /// Fetches records `chunk_size` at a time until `take` records have been returned or no more records are found.
+    /// The query's `cursor` and `skip` only apply to the first chunk,
+    /// after which each chunk starts after the last record of the previous one.
+    /// Queries using `last` aren't supported and return [`QueryError::Unsupported`](super::QueryError::Unsupported).
+    pub fn stream(self, chunk_size: i64) -> impl Stream<Item = super::Result<Actions::Data>> + 'a
+    where
+        Actions: 'a,
+        Actions::Where: Clone,
+        Actions::With: Clone,
+        Actions::OrderBy: Clone,
+        Actions::Cursor: Clone,
+        Actions::ScalarField: Clone,
+    {
+        self.chunks(chunk_size)
+            .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    /// Executes the query as multiple queries of at most `chunk_size` records in the same way as `stream`,
+    /// collecting the results. Useful for staying under database parameter limits
+    /// when fetching large numbers of records with relations.
+    pub async fn exec_in_batches(self, chunk_size: i64) -> super::Result<Vec<Actions::Data>>
+    where
+        Actions: 'a,
+        Actions::Where: Clone,
+        Actions::With: Clone,
+        Actions::OrderBy: Clone,
+        Actions::Cursor: Clone,
+        Actions::ScalarField: Clone,
+    {
+        self.chunks(chunk_size).try_concat().await
+    }
+
+    fn chunks(self, chunk_size: i64) -> impl Stream<Item = super::Result<Vec<Actions::Data>>> + 'a
+    where
+        Actions: 'a,
+        Actions::Where: Clone,
+        Actions::With: Clone,
+        Actions::OrderBy: Clone,
+        Actions::Cursor: Clone,
+        Actions::ScalarField: Clone,
+    {
+        let limit = self.take;
+
+        stream::try_unfold((Some(self), 0), move |(query, fetched)| async move {
+            let query = match query {
+                Some(query) => query,
+                None => return Ok::<_, super::QueryError>(None),
+            };
+
+            let take = match limit {
+                // chunks are fetched forwards from the cursor, so a negative take can't be split up
+                Some(limit) if limit < 0 => {
+                    return Err(super::QueryError::Unsupported(
+                        "streaming or batching a query that uses last".to_string(),
+                    ))
+                }
+                Some(limit) => chunk_size.min(limit - fetched),
+                None => chunk_size,
+            };
+
+            if take <= 0 {
+                return Ok(None);
+            }
+
+            let next = query.clone();
+
+            let items = Self {
+                take: Some(take),
+                ..query
+            }
+            .exec()
+            .await?;
+            let count = items.len() as i64;
+
+            // a cursor is used rather than an offset so that records aren't skipped or repeated
+            // if records before it are created or deleted
+            let next = items.last().filter(|_| count == take).map(|last| Self {
+                cursor_params: vec![Actions::cursor(last)],
+                skip: Some(1),
+                ..next
+            });
+
+            Ok(Some((items, (next, fetched + count))))
+        })
+    }
+}
+
+/// A page of records returned by [`FindMany::paginate`]
+#[derive(Debug, Clone, Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+    /// The total number of records matching the query's filters
+    pub total: i64,
+    pub page: i64,
+    pub per_page: i64,
+    pub page_count: i64,
 }
 
 impl<'a, Actions: ModelTypes> QueryConvert for FindMany<'a, Actions> {
@@ -167,7 +391,7 @@ impl<'a, Actions: ModelTypes> Query<'a> for FindMany<'a, Actions> {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
         let mut scalar_selections = Actions::scalar_selections();
 
-        scalar_selections.extend(self.with_params.into_iter().map(Into::into));
+        scalar_selections.extend(Actions::with_selections(self.with_params));
 
         (
             Operation::Read(Self::to_selection(
@@ -176,6 +400,7 @@ impl<'a, Actions: ModelTypes> Query<'a> for FindMany<'a, Actions> {
                 self.cursor_params,
                 self.skip,
                 self.take,
+                self.distinct,
                 scalar_selections,
             )),
             self.client,
@@ -221,7 +446,7 @@ impl<'a, Actions: ModelTypes> PaginatedQuery<'a> for FindMany<'a, Actions> {
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct ManyArgs<Actions: ModelTypes> {
     pub where_params: Vec<Actions::Where>,
     pub with_params: Vec<Actions::With>,
@@ -229,6 +454,7 @@ pub struct ManyArgs<Actions: ModelTypes> {
     pub cursor_params: Vec<Actions::Cursor>,
     pub skip: Option<i64>,
     pub take: Option<i64>,
+    pub distinct: Vec<Actions::ScalarField>,
 }
 
 impl<Actions: ModelTypes> ManyArgs<Actions> {
@@ -240,6 +466,7 @@ impl<Actions: ModelTypes> ManyArgs<Actions> {
             cursor_params: vec![],
             skip: None,
             take: None,
+            distinct: vec![],
         }
     }
 
@@ -268,6 +495,18 @@ impl<Actions: ModelTypes> ManyArgs<Actions> {
         self
     }
 
+    /// Takes the last `n` records, relative to the cursor if one is provided.
+    /// Records are still returned in the order specified by `order_by`.
+    pub fn last(mut self, n: i64) -> Self {
+        self.take = Some(-n);
+        self
+    }
+
+    pub fn distinct(mut self, fields: Vec<Actions::ScalarField>) -> Self {
+        self.distinct.extend(fields);
+        self
+    }
+
     pub fn to_graphql(self) -> (Vec<(String, ArgumentValue)>, Vec<Selection>) {
         let arguments = [
             (!self.where_params.is_empty()).then(|| {
@@ -313,13 +552,25 @@ impl<Actions: ModelTypes> ManyArgs<Actions> {
                 .map(|skip| ("skip".to_string(), PrismaValue::Int(skip).into())),
             self.take
                 .map(|take| ("take".to_string(), PrismaValue::Int(take).into())),
+            (!self.distinct.is_empty()).then(|| {
+                (
+                    "distinct".to_string(),
+                    PrismaValue::List(
+                        self.distinct
+                            .into_iter()
+                            .map(|field| PrismaValue::Enum(field.to_string()))
+                            .collect(),
+                    )
+                    .into(),
+                )
+            }),
         ]
         .into_iter()
         .flatten()
         .collect();
 
         let nested_selections = (self.with_params.len() > 0)
-            .then(|| self.with_params.into_iter().map(Into::into).collect())
+            .then(|| Actions::with_selections(self.with_params))
             .unwrap_or_default();
 
         (arguments, nested_selections)
@@ -15,6 +15,35 @@ pub struct FindUnique<'a, Actions: ModelTypes> {
     _data: PhantomData<(Actions::Set, Actions::Data)>,
 }
 
+impl<'a, Actions: ModelTypes> Clone for FindUnique<'a, Actions>
+where
+    Actions::Where: Clone,
+    Actions::With: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            where_param: self.where_param.clone(),
+            with_params: self.with_params.clone(),
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for FindUnique<'a, Actions>
+where
+    Actions::Where: std::fmt::Debug,
+    Actions::With: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FindUnique")
+            .field("model", &Actions::MODEL)
+            .field("where_param", &self.where_param)
+            .field("with_params", &self.with_params)
+            .finish()
+    }
+}
+
 impl<'a, Actions: ModelTypes> FindUnique<'a, Actions> {
     pub fn new(client: &'a PrismaClientInternals, where_param: Actions::Where) -> Self {
         Self {
@@ -69,6 +98,13 @@ impl<'a, Actions: ModelTypes> FindUnique<'a, Actions> {
     pub async fn exec(self) -> super::Result<Option<Actions::Data>> {
         super::exec(self).await
     }
+
+    /// Like `exec`, but returns a `RecordRequiredButNotFound` (P2025) error if no record is found.
+    pub async fn exec_or_throw(self) -> super::Result<Actions::Data> {
+        self.exec()
+            .await?
+            .ok_or_else(super::QueryError::record_not_found)
+    }
 }
 
 impl<'a, Actions: ModelTypes> QueryConvert for FindUnique<'a, Actions> {
@@ -84,7 +120,7 @@ impl<'a, Actions: ModelTypes> Query<'a> for FindUnique<'a, Actions> {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
         let mut scalar_selections = Actions::scalar_selections();
 
-        scalar_selections.extend(self.with_params.into_iter().map(Into::into));
+        scalar_selections.extend(Actions::with_selections(self.with_params));
 
         (
             Operation::Read(Self::to_selection(self.where_param, scalar_selections)),
@@ -105,7 +141,7 @@ impl<'a, Actions: ModelTypes> WithQuery<'a> for FindUnique<'a, Actions> {
     }
 }
 
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct UniqueArgs<Actions>
 where
     Actions: ModelTypes,
@@ -0,0 +1,204 @@
+use prisma_models::PrismaValue;
+use query_core::{Operation, Selection};
+
+use crate::{
+    aggregate_selections, merge_fields, ModelOperation, ModelQuery, ModelReadOperation,
+    ModelTypes, PrismaClientInternals, Query, QueryConvert, WhereInput, WhereQuery,
+};
+
+pub struct GroupBy<'a, Actions: ModelTypes> {
+    client: &'a PrismaClientInternals,
+    pub by: Vec<Actions::ScalarField>,
+    pub where_params: Vec<Actions::Where>,
+    pub order_by_params: Vec<Actions::OrderByWithAggregation>,
+    pub aggregate_params: Vec<Actions::Aggregate>,
+    pub having_params: Vec<Actions::Having>,
+    pub skip: Option<i64>,
+    pub take: Option<i64>,
+}
+
+impl<'a, Actions: ModelTypes> Clone for GroupBy<'a, Actions>
+where
+    Actions::ScalarField: Clone,
+    Actions::Where: Clone,
+    Actions::OrderByWithAggregation: Clone,
+    Actions::Aggregate: Clone,
+    Actions::Having: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            by: self.by.clone(),
+            where_params: self.where_params.clone(),
+            order_by_params: self.order_by_params.clone(),
+            aggregate_params: self.aggregate_params.clone(),
+            having_params: self.having_params.clone(),
+            skip: self.skip,
+            take: self.take,
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for GroupBy<'a, Actions>
+where
+    Actions::ScalarField: std::fmt::Debug,
+    Actions::Where: std::fmt::Debug,
+    Actions::OrderByWithAggregation: std::fmt::Debug,
+    Actions::Aggregate: std::fmt::Debug,
+    Actions::Having: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GroupBy")
+            .field("model", &Actions::MODEL)
+            .field("by", &self.by)
+            .field("where_params", &self.where_params)
+            .field("order_by_params", &self.order_by_params)
+            .field("aggregate_params", &self.aggregate_params)
+            .field("having_params", &self.having_params)
+            .field("skip", &self.skip)
+            .field("take", &self.take)
+            .finish()
+    }
+}
+
+impl<'a, Actions: ModelTypes> GroupBy<'a, Actions> {
+    pub fn new(
+        client: &'a PrismaClientInternals,
+        by: Vec<Actions::ScalarField>,
+        where_params: Vec<Actions::Where>,
+    ) -> Self {
+        Self {
+            client,
+            by,
+            where_params,
+            order_by_params: vec![],
+            aggregate_params: vec![],
+            having_params: vec![],
+            skip: None,
+            take: None,
+        }
+    }
+
+    pub fn with(mut self, param: impl Into<Actions::Aggregate>) -> Self {
+        self.aggregate_params.push(param.into());
+        self
+    }
+
+    pub fn order_by(mut self, param: Actions::OrderByWithAggregation) -> Self {
+        self.order_by_params.push(param);
+        self
+    }
+
+    pub fn having(mut self, param: impl Into<Actions::Having>) -> Self {
+        self.having_params.push(param.into());
+        self
+    }
+
+    pub fn skip(mut self, skip: i64) -> Self {
+        self.skip = Some(skip);
+        self
+    }
+
+    pub fn take(mut self, take: i64) -> Self {
+        self.take = Some(take);
+        self
+    }
+
+    pub async fn exec(self) -> super::Result<Vec<Actions::GroupByData>> {
+        super::exec(self).await
+    }
+}
+
+impl<'a, Actions: ModelTypes> QueryConvert for GroupBy<'a, Actions> {
+    type RawType = Vec<Actions::GroupByData>;
+    type ReturnValue = Self::RawType;
+
+    fn convert(raw: Self::RawType) -> super::Result<Self::ReturnValue> {
+        Ok(raw)
+    }
+}
+
+impl<'a, Actions: ModelTypes> Query<'a> for GroupBy<'a, Actions> {
+    fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
+        let by = self
+            .by
+            .into_iter()
+            .map(|field| field.to_string())
+            .collect::<Vec<_>>();
+
+        let nested_selections = by
+            .iter()
+            .map(|field| Selection::new(field, None, [], []))
+            .chain(aggregate_selections(self.aggregate_params))
+            .collect::<Vec<_>>();
+
+        (
+            Operation::Read(Self::base_selection(
+                [
+                    Some((
+                        "by".to_string(),
+                        PrismaValue::List(by.into_iter().map(PrismaValue::Enum).collect()).into(),
+                    )),
+                    (!self.where_params.is_empty()).then(|| {
+                        (
+                            "where".to_string(),
+                            PrismaValue::Object(merge_fields(
+                                self.where_params
+                                    .into_iter()
+                                    .map(WhereInput::serialize)
+                                    .map(|s| (s.field, s.value.into()))
+                                    .collect(),
+                            ))
+                            .into(),
+                        )
+                    }),
+                    (!self.having_params.is_empty()).then(|| {
+                        (
+                            "having".to_string(),
+                            PrismaValue::Object(merge_fields(
+                                self.having_params
+                                    .into_iter()
+                                    .map(WhereInput::serialize)
+                                    .map(|s| (s.field, s.value.into()))
+                                    .collect(),
+                            ))
+                            .into(),
+                        )
+                    }),
+                    (!self.order_by_params.is_empty()).then(|| {
+                        (
+                            "orderBy".to_string(),
+                            PrismaValue::List(
+                                self.order_by_params
+                                    .into_iter()
+                                    .map(|p| PrismaValue::Object(vec![p.into()]))
+                                    .collect(),
+                            )
+                            .into(),
+                        )
+                    }),
+                    self.skip
+                        .map(|skip| ("skip".to_string(), PrismaValue::Int(skip).into())),
+                    self.take
+                        .map(|take| ("take".to_string(), PrismaValue::Int(take).into())),
+                ]
+                .into_iter()
+                .flatten(),
+                nested_selections,
+            )),
+            self.client,
+        )
+    }
+}
+
+impl<'a, Actions: ModelTypes> ModelQuery<'a> for GroupBy<'a, Actions> {
+    type Types = Actions;
+
+    const TYPE: ModelOperation = ModelOperation::Read(ModelReadOperation::GroupBy);
+}
+
+impl<'a, Actions: ModelTypes> WhereQuery<'a> for GroupBy<'a, Actions> {
+    fn add_where(&mut self, param: Actions::Where) {
+        self.where_params.push(param);
+    }
+}
@@ -1,4 +1,7 @@
+mod advisory_lock;
+mod aggregate;
 mod batch;
+mod constraints;
 mod count;
 mod create;
 mod create_many;
@@ -10,17 +13,23 @@ mod execute_raw;
 mod find_first;
 mod find_many;
 mod find_unique;
+mod group_by;
 mod include;
 mod mongo_raw;
 mod query;
 mod query_raw;
+mod query_string;
 mod select;
 mod update;
 mod update_many;
 mod update_unchecked;
 mod upsert;
+mod upsert_many;
 
+pub use advisory_lock::*;
+pub use aggregate::*;
 pub use batch::*;
+pub use constraints::*;
 pub use count::*;
 pub use create::*;
 pub use create_many::*;
@@ -32,15 +41,18 @@ pub use execute_raw::*;
 pub use find_first::*;
 pub use find_many::*;
 pub use find_unique::*;
+pub use group_by::*;
 pub use include::*;
 pub use mongo_raw::*;
 pub use query::*;
 pub use query_raw::*;
+pub use query_string::*;
 pub use select::*;
 pub use update::*;
 pub use update_many::*;
 pub use update_unchecked::*;
 pub use upsert::*;
+pub use upsert_many::*;
 
 use futures::FutureExt;
 pub use query_core::{schema::QuerySchemaRef, Operation, Selection};
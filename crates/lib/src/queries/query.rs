@@ -2,7 +2,7 @@ use prisma_models::PrismaValue;
 use query_core::{Operation, Selection, SelectionArgument};
 use serde::de::DeserializeOwned;
 
-use crate::{PrismaClientInternals, WhereInput};
+use crate::{AggregateOperation, PrismaClientInternals, WhereInput};
 
 pub trait QueryConvert {
     type RawType: Data;
@@ -15,6 +15,35 @@ pub trait QueryConvert {
 
 pub trait Query<'a>: QueryConvert {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals);
+
+    /// The operations the query is executed as in a batch.
+    /// Queries that are too large for a single operation, such as `create_many`, can be split up.
+    fn batch_operations(self) -> Vec<Operation>
+    where
+        Self: Sized,
+    {
+        vec![self.graphql().0]
+    }
+
+    /// Combines the results of the query's `batch_operations` into its return value
+    fn batch_convert(raw: Vec<Self::RawType>) -> super::Result<Self::ReturnValue>
+    where
+        Self: Sized,
+    {
+        Self::convert(
+            raw.into_iter()
+                .next()
+                .expect("Queries have at least one operation"),
+        )
+    }
+
+    /// Renders the operation that the query would execute, without executing it.
+    fn into_query_string(self) -> String
+    where
+        Self: Sized,
+    {
+        super::operation_to_string(&self.graphql().0)
+    }
 }
 
 pub trait ModelTypes {
@@ -25,10 +54,23 @@ pub trait ModelTypes {
     type With: Into<Selection>;
     type OrderBy: Into<(String, PrismaValue)>;
     type Cursor: Into<Self::Where>;
+    type Aggregate: Into<(AggregateOperation, String)>;
+    type AggregateData: Data;
+    type ScalarField: ToString;
+    type OrderByWithAggregation: Into<(String, PrismaValue)>;
+    type GroupByData: Data;
+    type Having: WhereInput;
 
     const MODEL: &'static str;
 
     fn scalar_selections() -> Vec<Selection>;
+
+    /// A cursor pointing at the record, used to continue paginating after it
+    fn cursor(data: &Self::Data) -> Self::Cursor;
+
+    fn with_selections(params: Vec<Self::With>) -> Vec<Selection> {
+        params.into_iter().map(Into::into).collect()
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -37,6 +79,8 @@ pub enum ModelReadOperation {
     FindFirst,
     FindMany,
     Count,
+    Aggregate,
+    GroupBy,
 }
 
 impl ModelReadOperation {
@@ -45,7 +89,8 @@ impl ModelReadOperation {
             Self::FindUnique => "findUnique",
             Self::FindFirst => "findFirst",
             Self::FindMany => "findMany",
-            Self::Count => "aggregate",
+            Self::Count | Self::Aggregate => "aggregate",
+            Self::GroupBy => "groupBy",
         }
     }
 }
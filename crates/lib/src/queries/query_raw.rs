@@ -36,28 +36,32 @@ where
     }
 
     pub(crate) fn convert(raw: RawOperationData) -> super::Result<Vec<Data>> {
-        let typed_data: Vec<HashMap<String, RawPrismaValue>> = raw
-            .into_iter()
-            .map(|row| {
-                row.into_iter()
-                    .map(|(column_name, cell)| (column_name, cell.into()))
-                    .collect()
-            })
-            .collect();
+        raw.into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                // included in errors so that mismatched columns are easy to find
+                let mut columns = row
+                    .iter()
+                    .map(|(column_name, cell)| format!("{column_name}: {}", cell.typ))
+                    .collect::<Vec<_>>();
+                columns.sort();
 
-        typed_data
-            .into_iter()
-            .map(|row| {
-                let v = serde_value::to_value(&row)
-                    .map_err(|e| e.to_string())
-                    .map_err(QueryError::Deserialize)?;
+                let row: HashMap<String, RawPrismaValue> = row
+                    .into_iter()
+                    .map(|(column_name, cell)| (column_name, cell.into()))
+                    .collect();
 
-                v.deserialize_into::<Data>()
+                serde_value::to_value(&row)
                     .map_err(|e| e.to_string())
-                    .map_err(QueryError::Deserialize)
+                    .and_then(|v| v.deserialize_into::<Data>().map_err(|e| e.to_string()))
+                    .map_err(|e| {
+                        QueryError::Deserialize(format!(
+                            "row {i}: {e} (columns are {})",
+                            columns.join(", ")
+                        ))
+                    })
             })
-            .collect::<Result<_, _>>()
-            .map_err(Into::into)
+            .collect()
     }
 
     pub async fn exec(self) -> super::Result<Vec<Data>> {
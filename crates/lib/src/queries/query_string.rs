@@ -0,0 +1,114 @@
+use std::fmt::Write;
+
+use prisma_models::PrismaValue;
+use query_core::{ArgumentValue, Operation, Selection};
+
+/// Renders an operation in the GraphQL-like syntax of Prisma's query documents,
+/// eg. `query { findManyPost(take: 5) { id title } }`.
+pub fn operation_to_string(operation: &Operation) -> String {
+    let (kind, selection) = match operation {
+        Operation::Read(selection) => ("query", selection),
+        Operation::Write(selection) => ("mutation", selection),
+    };
+
+    let mut out = format!("{kind} {{\n");
+    write_selection(&mut out, selection, 1);
+    out.push('}');
+
+    out
+}
+
+fn write_selection(out: &mut String, selection: &Selection, depth: usize) {
+    let indent = "  ".repeat(depth);
+
+    out.push_str(&indent);
+
+    if let Some(alias) = selection.alias() {
+        write!(out, "{alias}: ").ok();
+    }
+
+    out.push_str(selection.name());
+
+    if !selection.arguments().is_empty() {
+        let arguments = selection
+            .arguments()
+            .iter()
+            .map(|(name, value)| format!("{name}: {}", argument_to_string(value)))
+            .collect::<Vec<_>>();
+
+        write!(out, "({})", arguments.join(", ")).ok();
+    }
+
+    if !selection.nested_selections().is_empty() {
+        out.push_str(" {\n");
+
+        for nested in selection.nested_selections() {
+            write_selection(out, nested, depth + 1);
+        }
+
+        out.push_str(&indent);
+        out.push('}');
+    }
+
+    out.push('\n');
+}
+
+fn argument_to_string(value: &ArgumentValue) -> String {
+    match value {
+        ArgumentValue::Scalar(value) => value_to_string(value),
+        ArgumentValue::List(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(argument_to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ArgumentValue::Object(fields) => object_to_string(
+            fields
+                .iter()
+                .map(|(name, value)| (name, argument_to_string(value))),
+        ),
+        value => format!("{value:?}"),
+    }
+}
+
+fn value_to_string(value: &PrismaValue) -> String {
+    let quote = |s: &str| serde_json::to_string(s).unwrap();
+
+    match value {
+        PrismaValue::String(s) | PrismaValue::Json(s) | PrismaValue::Xml(s) => quote(s),
+        PrismaValue::Enum(s) => s.clone(),
+        PrismaValue::Boolean(b) => b.to_string(),
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => i.to_string(),
+        PrismaValue::Float(f) => f.to_string(),
+        PrismaValue::Uuid(u) => quote(&u.to_string()),
+        PrismaValue::DateTime(d) => quote(&d.to_rfc3339()),
+        PrismaValue::Bytes(b) => quote(&base64::encode(b)),
+        PrismaValue::Null => "null".to_string(),
+        PrismaValue::List(values) => format!(
+            "[{}]",
+            values
+                .iter()
+                .map(value_to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        PrismaValue::Object(fields) => object_to_string(
+            fields
+                .iter()
+                .map(|(name, value)| (name, value_to_string(value))),
+        ),
+    }
+}
+
+fn object_to_string<'a>(fields: impl Iterator<Item = (&'a String, String)>) -> String {
+    let fields = fields
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>();
+
+    match fields.is_empty() {
+        true => "{}".to_string(),
+        false => format!("{{ {} }}", fields.join(", ")),
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
 pub struct Update<'a, Actions: ModelTypes> {
     client: &'a PrismaClientInternals,
     pub where_param: Actions::Where,
+    pub where_filters: Vec<Actions::Where>,
     pub set_params: Vec<Actions::Set>,
     pub with_params: Vec<Actions::With>,
 }
@@ -24,6 +25,7 @@ impl<'a, Actions: ModelTypes> Update<'a, Actions> {
         Self {
             client,
             where_param,
+            where_filters: vec![],
             set_params,
             with_params,
         }
@@ -34,8 +36,17 @@ impl<'a, Actions: ModelTypes> Update<'a, Actions> {
         self
     }
 
+    /// Adds a non-unique filter that the record must also match to be updated,
+    /// failing with a `RecordRequiredButNotFound` error if it doesn't.
+    /// Requires the `extendedWhereUnique` preview feature.
+    pub fn where_filter(mut self, param: Actions::Where) -> Self {
+        self.where_filters.push(param);
+        self
+    }
+
     fn to_selection(
         where_param: Actions::Where,
+        where_filters: Vec<Actions::Where>,
         set_params: Vec<Actions::Set>,
         nested_selections: impl IntoIterator<Item = Selection>,
     ) -> Selection {
@@ -43,7 +54,16 @@ impl<'a, Actions: ModelTypes> Update<'a, Actions> {
             [
                 (
                     "where".to_string(),
-                    PrismaValue::Object(vec![where_param.serialize().transform_equals()]).into(),
+                    PrismaValue::Object(merge_fields(
+                        std::iter::once(where_param.serialize().transform_equals())
+                            .chain(where_filters.into_iter().map(|param| {
+                                let param = param.serialize();
+
+                                (param.field, param.value.into())
+                            }))
+                            .collect(),
+                    ))
+                    .into(),
                 ),
                 (
                     "data".to_string(),
@@ -65,6 +85,7 @@ impl<'a, Actions: ModelTypes> Update<'a, Actions> {
             self.client,
             Operation::Write(Self::to_selection(
                 self.where_param,
+                self.where_filters,
                 self.set_params,
                 select.to_selections(),
             )),
@@ -79,6 +100,7 @@ impl<'a, Actions: ModelTypes> Update<'a, Actions> {
             self.client,
             Operation::Write(Self::to_selection(
                 self.where_param,
+                self.where_filters,
                 self.set_params,
                 include.to_selections(),
             )),
@@ -103,11 +125,12 @@ impl<'a, Actions: ModelTypes> Query<'a> for Update<'a, Actions> {
     fn graphql(self) -> (Operation, &'a PrismaClientInternals) {
         let mut scalar_selections = Actions::scalar_selections();
 
-        scalar_selections.extend(self.with_params.into_iter().map(Into::into));
+        scalar_selections.extend(Actions::with_selections(self.with_params));
 
         (
             Operation::Write(Self::to_selection(
                 self.where_param,
+                self.where_filters,
                 self.set_params,
                 scalar_selections,
             )),
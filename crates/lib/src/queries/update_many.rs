@@ -12,6 +12,34 @@ pub struct UpdateMany<'a, Actions: ModelTypes> {
     pub set_params: Vec<Actions::Set>,
 }
 
+impl<'a, Actions: ModelTypes> Clone for UpdateMany<'a, Actions>
+where
+    Actions::Where: Clone,
+    Actions::Set: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            where_params: self.where_params.clone(),
+            set_params: self.set_params.clone(),
+        }
+    }
+}
+
+impl<'a, Actions: ModelTypes> std::fmt::Debug for UpdateMany<'a, Actions>
+where
+    Actions::Where: std::fmt::Debug,
+    Actions::Set: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdateMany")
+            .field("model", &Actions::MODEL)
+            .field("where_params", &self.where_params)
+            .field("set_params", &self.set_params)
+            .finish()
+    }
+}
+
 impl<'a, Actions: ModelTypes> UpdateMany<'a, Actions> {
     pub fn new(
         client: &'a PrismaClientInternals,
@@ -25,6 +53,16 @@ impl<'a, Actions: ModelTypes> UpdateMany<'a, Actions> {
         }
     }
 
+    pub fn where_(mut self, param: impl Into<Actions::Where>) -> Self {
+        self.where_params.push(param.into());
+        self
+    }
+
+    pub fn extend_where(mut self, params: impl IntoIterator<Item = Actions::Where>) -> Self {
+        self.where_params.extend(params);
+        self
+    }
+
     pub async fn exec(self) -> super::Result<i64> {
         super::exec(self).await
     }
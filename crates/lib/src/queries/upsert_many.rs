@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+use crate::{FindUnique, ModelTypes, PrismaClientInternals, Upsert};
+
+/// Multiple upserts that are executed together in a single batch,
+/// returning the outcome of each upsert in the order they were provided.
+pub struct UpsertMany<'a, Actions: ModelTypes> {
+    client: &'a PrismaClientInternals,
+    pub upserts: Vec<Upsert<'a, Actions>>,
+}
+
+impl<'a, Actions: ModelTypes> UpsertMany<'a, Actions> {
+    pub fn new(client: &'a PrismaClientInternals, upserts: Vec<Upsert<'a, Actions>>) -> Self {
+        Self { client, upserts }
+    }
+
+    /// Executes all of the upserts in one transaction,
+    /// so either all of them are committed or none of them are.
+    pub async fn exec(self) -> super::Result<Vec<UpsertOutcome<Actions::Data>>>
+    where
+        Actions::Where: Clone,
+    {
+        let Self { client, upserts } = self;
+
+        // Prisma doesn't report whether an upsert created or updated its record,
+        // so each upsert is preceded by a lookup of its record in the same transaction
+        let queries = upserts
+            .into_iter()
+            .map(|upsert| (FindUnique::new(client, upsert.where_param.clone()), upsert))
+            .collect::<Vec<(FindUnique<'a, Actions>, _)>>();
+
+        Ok(super::batch(queries, client)
+            .await?
+            .into_iter()
+            .map(|(existing, data)| match existing {
+                Some(_) => UpsertOutcome::Updated(data),
+                None => UpsertOutcome::Created(data),
+            })
+            .collect())
+    }
+}
+
+/// Whether an upsert of [`UpsertMany`] created a new record or updated an existing one
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "outcome", content = "data", rename_all = "camelCase")]
+pub enum UpsertOutcome<Data> {
+    Created(Data),
+    Updated(Data),
+}
+
+impl<Data> UpsertOutcome<Data> {
+    pub fn is_created(&self) -> bool {
+        matches!(self, Self::Created(_))
+    }
+
+    pub fn data(&self) -> &Data {
+        match self {
+            Self::Created(data) | Self::Updated(data) => data,
+        }
+    }
+
+    pub fn into_data(self) -> Data {
+        match self {
+            Self::Created(data) | Self::Updated(data) => data,
+        }
+    }
+}
@@ -51,7 +51,7 @@ impl Raw {
             query = query.replacen("{}", &variable_indicator, 1);
         }
 
-        // TODO: do this at compile time
+        // queries can be built at runtime, so their parameters can only be counted here
         if query.contains("{}") {
             panic!("Raw query has not been given enough parameters");
         }
@@ -60,12 +60,42 @@ impl Raw {
     }
 }
 
+/// Creates a comma separated list of `len` placeholders,
+/// for expanding a list of values in queries like `WHERE id IN ({})`.
+/// An empty list isn't valid SQL, so queries with no values should be handled separately.
+pub fn list_placeholders(len: usize) -> String {
+    vec!["{}"; len].join(", ")
+}
+
+/// Formats a vector as a pgvector literal such as `[1,2,3]`,
+/// for use as a raw query parameter that is cast with `::vector`.
+pub fn pg_vector(values: &[f32]) -> PrismaValue {
+    PrismaValue::String(format!(
+        "[{}]",
+        values
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    ))
+}
+
+/// A record found by a nearest neighbour query, along with its distance from the embedding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Nearest<Data> {
+    #[serde(flatten)]
+    pub data: Data,
+    // prefixed so that it can't conflict with the model's fields
+    #[serde(rename = "_distance")]
+    pub distance: f64,
+}
+
 pub type RawOperationData = Vec<HashMap<String, RawTypedJson>>;
 
 #[derive(Deserialize)]
 pub struct RawTypedJson {
     #[serde(rename = "prisma__type")]
-    typ: String,
+    pub(crate) typ: String,
     #[serde(rename = "prisma__value")]
     value: serde_json::Value,
 }
@@ -1,15 +1,76 @@
-use std::{future::Future, marker::PhantomData};
+use std::{
+    future::Future,
+    marker::PhantomData,
+    panic::{resume_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use futures::FutureExt;
 
 use query_core::{protocol::EngineProtocol, TransactionOptions, TxId};
 
-use crate::{ExecutionEngine, PrismaClient, PrismaClientInternals, QueryError};
+use crate::{ExecuteRaw, ExecutionEngine, PrismaClient, PrismaClientInternals, QueryError, Raw};
+
+/// Used to give each savepoint of a nested transaction a unique name
+static SAVEPOINT_ID: AtomicUsize = AtomicUsize::new(0);
+
+const DEFAULT_TIMEOUT: u64 = 5000;
+const DEFAULT_MAX_WAIT: u64 = 2000;
+
+/// SQL Server has its own syntax for savepoints, and doesn't release them
+enum SavepointSyntax {
+    Standard,
+    SqlServer,
+}
+
+impl SavepointSyntax {
+    /// MongoDB doesn't support raw SQL, so it has no savepoints
+    fn for_url(url: &str) -> Option<Self> {
+        if url.starts_with("mongodb") {
+            None
+        } else if url.starts_with("sqlserver") {
+            Some(Self::SqlServer)
+        } else {
+            Some(Self::Standard)
+        }
+    }
+
+    fn create(&self, savepoint: &str) -> String {
+        match self {
+            Self::Standard => format!("SAVEPOINT {savepoint}"),
+            Self::SqlServer => format!("SAVE TRANSACTION {savepoint}"),
+        }
+    }
+
+    fn release(&self, savepoint: &str) -> Option<String> {
+        match self {
+            Self::Standard => Some(format!("RELEASE SAVEPOINT {savepoint}")),
+            Self::SqlServer => None,
+        }
+    }
+
+    fn rollback(&self, savepoint: &str) -> String {
+        match self {
+            Self::Standard => format!("ROLLBACK TO SAVEPOINT {savepoint}"),
+            Self::SqlServer => format!("ROLLBACK TRANSACTION {savepoint}"),
+        }
+    }
+}
+
+/// The engine starts transactions before the client can run any queries in them,
+/// and only PostgreSQL and CockroachDB can make a transaction read-only once it has started
+fn supports_read_only(url: &str) -> bool {
+    url.starts_with("postgres")
+}
 
 pub struct TransactionBuilder<'a, TClient> {
     client: &'a TClient,
     internals: &'a PrismaClientInternals,
-    timeout: u64,
-    max_wait: u64,
+    timeout: Option<u64>,
+    max_wait: Option<u64>,
     isolation_level: Option<String>,
+    read_only: bool,
 }
 
 impl<'a, TClient: PrismaClient> TransactionBuilder<'a, TClient> {
@@ -17,18 +78,25 @@ impl<'a, TClient: PrismaClient> TransactionBuilder<'a, TClient> {
         Self {
             client,
             internals,
-            timeout: 5000,
-            max_wait: 2000,
+            timeout: None,
+            max_wait: None,
             isolation_level: None,
+            read_only: false,
         }
     }
 
     pub fn with_timeout(self, timeout: u64) -> Self {
-        Self { timeout, ..self }
+        Self {
+            timeout: Some(timeout),
+            ..self
+        }
     }
 
     pub fn with_max_wait(self, max_wait: u64) -> Self {
-        Self { max_wait, ..self }
+        Self {
+            max_wait: Some(max_wait),
+            ..self
+        }
     }
 
     pub fn with_isolation_level(self, isolation_level: impl TransactionIsolationLevel) -> Self {
@@ -38,6 +106,15 @@ impl<'a, TClient: PrismaClient> TransactionBuilder<'a, TClient> {
         }
     }
 
+    /// Makes the transaction read-only, so that any writes inside it fail.
+    /// Only supported on PostgreSQL and CockroachDB, other databases return [`QueryError::Unsupported`].
+    pub fn read_only(self) -> Self {
+        Self {
+            read_only: true,
+            ..self
+        }
+    }
+
     pub async fn run<TErr, TRet, TFut, TFn>(self, tx: TFn) -> Result<TRet, TErr>
     where
         TFut: Future<Output = Result<TRet, TErr>>,
@@ -45,19 +122,101 @@ impl<'a, TClient: PrismaClient> TransactionBuilder<'a, TClient> {
         TErr: From<crate::QueryError>,
     {
         match &self.internals.engine {
+            // the engine doesn't support nested transactions, so a savepoint is used instead
+            ExecutionEngine::Real {
+                tx_id: Some(tx_id), ..
+            } => {
+                // savepoints can't have their own options, they're taken from the outer transaction
+                if self.timeout.is_some()
+                    || self.max_wait.is_some()
+                    || self.isolation_level.is_some()
+                    || self.read_only
+                {
+                    return Err(QueryError::Unsupported(
+                        "setting the timeout, max wait, isolation level or read-only mode of a nested transaction"
+                            .to_string(),
+                    )
+                    .into());
+                }
+
+                let syntax = SavepointSyntax::for_url(self.internals.url()).ok_or_else(|| {
+                    QueryError::Unsupported("nested transactions on MongoDB".to_string())
+                })?;
+
+                let savepoint = format!(
+                    "prisma_client_rust_{}",
+                    SAVEPOINT_ID.fetch_add(1, Ordering::Relaxed)
+                );
+
+                Self::execute_sql(self.internals, &syntax.create(&savepoint)).await?;
+
+                let result = AssertUnwindSafe(tx(self.client.with_tx_id(Some(tx_id.clone()))))
+                    .catch_unwind()
+                    .await;
+
+                match result {
+                    Ok(Ok(value)) => {
+                        if let Some(release) = syntax.release(&savepoint) {
+                            if let Err(e) = Self::execute_sql(self.internals, &release).await {
+                                // undo the savepoint's changes so they aren't committed with the outer transaction
+                                Self::execute_sql(self.internals, &syntax.rollback(&savepoint))
+                                    .await?;
+
+                                return Err(e.into());
+                            }
+                        }
+
+                        Ok(value)
+                    }
+                    Ok(Err(e)) => {
+                        // if the rollback fails the outer transaction is left in an unknown state,
+                        // which is more important to report than the closure's error
+                        Self::execute_sql(self.internals, &syntax.rollback(&savepoint)).await?;
+
+                        Err(e)
+                    }
+                    Err(panic) => {
+                        // the panic is resumed regardless, so a failed rollback can't be reported
+                        Self::execute_sql(self.internals, &syntax.rollback(&savepoint))
+                            .await
+                            .ok();
+
+                        resume_unwind(panic)
+                    }
+                }
+            }
             ExecutionEngine::Real { connector, .. } => {
+                self.check_read_only()?;
+
                 let new_tx_id = connector
                     .executor
                     .start_tx(
                         connector.query_schema.clone(),
                         EngineProtocol::Graphql,
-                        TransactionOptions::new(self.max_wait, self.timeout, self.isolation_level),
+                        TransactionOptions::new(
+                            self.max_wait.unwrap_or(DEFAULT_MAX_WAIT),
+                            self.timeout.unwrap_or(DEFAULT_TIMEOUT),
+                            self.isolation_level,
+                        ),
                     )
                     .await
                     .map_err(|e| QueryError::Execute(e.into()))?;
 
-                match tx(self.client.with_tx_id(Some(new_tx_id.clone()))).await {
-                    result @ Ok(_) => {
+                let client = self.client.with_tx_id(Some(new_tx_id.clone()));
+
+                if self.read_only {
+                    if let Err(e) = Self::set_read_only(&client).await {
+                        connector.executor.rollback_tx(new_tx_id).await.ok();
+
+                        return Err(e.into());
+                    }
+                }
+
+                // rolling back on panic prevents the transaction from lingering until it times out
+                let result = AssertUnwindSafe(tx(client)).catch_unwind().await;
+
+                match result {
+                    Ok(result @ Ok(_)) => {
                         connector
                             .executor
                             .commit_tx(new_tx_id)
@@ -66,34 +225,115 @@ impl<'a, TClient: PrismaClient> TransactionBuilder<'a, TClient> {
 
                         result
                     }
-                    err @ Err(_) => {
+                    Ok(err @ Err(_)) => {
                         connector.executor.rollback_tx(new_tx_id).await.ok();
 
                         err
                     }
+                    Err(panic) => {
+                        connector.executor.rollback_tx(new_tx_id).await.ok();
+
+                        resume_unwind(panic)
+                    }
                 }
             }
             _ => tx(self.client.with_tx_id(None)).await,
         }
     }
 
+    async fn execute_sql(internals: &PrismaClientInternals, sql: &str) -> super::Result<i64> {
+        // the query has no parameters, so the database doesn't need to be known
+        ExecuteRaw::new(internals, Raw::new(sql, vec![]), "")
+            .exec()
+            .await
+    }
+
+    fn check_read_only(&self) -> super::Result<()> {
+        match self.read_only && !supports_read_only(self.internals.url()) {
+            true => Err(QueryError::Unsupported(
+                "read-only transactions on databases other than PostgreSQL and CockroachDB"
+                    .to_string(),
+            )),
+            false => Ok(()),
+        }
+    }
+
+    // must be executed before any other queries in the transaction
+    async fn set_read_only(client: &TClient) -> super::Result<()> {
+        Self::execute_sql(client.internals(), "SET TRANSACTION READ ONLY")
+            .await
+            .map(|_| ())
+    }
+
+    /// Runs the transaction like `run`, re-running it up to `max_retries` times if it fails due to a write conflict or deadlock.
+    /// Retries are delayed by 50ms, doubling after each attempt.
+    /// Nested transactions aren't retried, since a write conflict fails the outer transaction as well.
+    pub async fn run_with_retries<TErr, TRet, TFut, TFn>(
+        self,
+        max_retries: u32,
+        tx: TFn,
+    ) -> Result<TRet, TErr>
+    where
+        TFut: Future<Output = Result<TRet, TErr>>,
+        TFn: Fn(TClient) -> TFut,
+        TErr: RetryableError,
+    {
+        if self.internals.in_transaction() {
+            return self.run(tx).await;
+        }
+
+        let mut attempt = 0;
+
+        loop {
+            let builder = Self {
+                client: self.client,
+                internals: self.internals,
+                timeout: self.timeout,
+                max_wait: self.max_wait,
+                isolation_level: self.isolation_level.clone(),
+                read_only: self.read_only,
+            };
+
+            match builder.run(&tx).await {
+                Err(e) if e.is_write_conflict() && attempt < max_retries => {
+                    tokio::time::sleep(Duration::from_millis(50 << attempt.min(10))).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
     pub async fn begin(self) -> super::Result<(TransactionController<TClient>, TClient)> {
         Ok(match &self.internals.engine {
             ExecutionEngine::Real { connector, .. } => {
+                self.check_read_only()?;
+
                 let new_tx_id = connector
                     .executor
                     .start_tx(
                         connector.query_schema.clone(),
                         EngineProtocol::Graphql,
-                        TransactionOptions::new(self.max_wait, self.timeout, self.isolation_level),
+                        TransactionOptions::new(
+                            self.max_wait.unwrap_or(DEFAULT_MAX_WAIT),
+                            self.timeout.unwrap_or(DEFAULT_TIMEOUT),
+                            self.isolation_level,
+                        ),
                     )
                     .await
                     .map_err(|e| QueryError::Execute(e.into()))?;
 
-                (
-                    TransactionController::new(new_tx_id.clone()),
-                    self.client.with_tx_id(Some(new_tx_id)),
-                )
+                let client = self.client.with_tx_id(Some(new_tx_id.clone()));
+
+                if self.read_only {
+                    if let Err(e) = Self::set_read_only(&client).await {
+                        connector.executor.rollback_tx(new_tx_id).await.ok();
+
+                        return Err(e);
+                    }
+                }
+
+                (TransactionController::new(new_tx_id), client)
             }
             _ => (
                 TransactionController::new("".to_string().into()),
@@ -138,3 +378,15 @@ impl<TClient: PrismaClient> TransactionController<TClient> {
 }
 
 pub trait TransactionIsolationLevel: ToString {}
+
+/// Errors that can be returned from transactions run with `run_with_retries`,
+/// which need to say whether they were caused by a write conflict so it can be retried
+pub trait RetryableError: From<QueryError> {
+    fn is_write_conflict(&self) -> bool;
+}
+
+impl RetryableError for QueryError {
+    fn is_write_conflict(&self) -> bool {
+        QueryError::is_write_conflict(self)
+    }
+}
@@ -19,6 +19,7 @@ pub struct GenerateArgs<'a> {
     pub read_filters: Vec<Filter<'a>>,
     pub write_params: Vec<Filter<'a>>,
     pub connector: &'static dyn Connector,
+    pub bigint_type: BigIntType,
 }
 
 impl<'a> GenerateArgs<'a> {
@@ -77,6 +78,8 @@ impl<'a> GenerateArgs<'a> {
 
             for enm in schema.db.walk_enums() {
                 let possible_filters = [
+                    "Enum".to_string() + &enm.ast_enum().name.name + "ListFilter",
+                    "Enum".to_string() + &enm.ast_enum().name.name + "NullableListFilter",
                     "Enum".to_string() + &enm.ast_enum().name.name + "Filter",
                     "Enum".to_string() + &enm.ast_enum().name.name + "NullableFilter",
                 ];
@@ -236,6 +239,10 @@ impl<'a> GenerateArgs<'a> {
                                             TypeLocation::Scalar if input_type.typ != "null" => {
                                                 scalar_name = Some(input_type.typ.clone() + "List");
                                             }
+                                            // enum lists are named after the enum, like `write_param` expects
+                                            TypeLocation::EnumTypes => {
+                                                scalar_name = Some(input_type.typ.clone() + "List");
+                                            }
                                             _ => {}
                                         }
                                     }
@@ -248,6 +255,7 @@ impl<'a> GenerateArgs<'a> {
                                     .iter()
                                     .find(|input_type| match input_type.location {
                                         TypeLocation::Scalar if input_type.typ != "null" => true,
+                                        TypeLocation::EnumTypes => true,
                                         _ => false,
                                     })
                                     .map(|_| field)
@@ -293,6 +301,7 @@ impl<'a> GenerateArgs<'a> {
             read_filters,
             write_params: write_filters,
             connector,
+            bigint_type: BigIntType::default(),
         }
     }
 
@@ -347,8 +356,8 @@ impl DmmfSchemaExt for DmmfSchema {
 
 pub trait DmmfInputFieldExt {
     fn arity(&self) -> FieldArity;
-    fn type_tokens(&self, prefix: &TokenStream) -> TokenStream;
-    fn to_prisma_value(&self, var: &Ident) -> TokenStream;
+    fn type_tokens(&self, prefix: &TokenStream, bigint_type: BigIntType) -> TokenStream;
+    fn to_prisma_value(&self, var: &Ident, bigint_type: BigIntType) -> TokenStream;
 }
 
 impl DmmfInputFieldExt for DmmfInputField {
@@ -368,7 +377,7 @@ impl DmmfInputFieldExt for DmmfInputField {
         }
     }
 
-    fn type_tokens(&self, prefix: &TokenStream) -> TokenStream {
+    fn type_tokens(&self, prefix: &TokenStream, bigint_type: BigIntType) -> TokenStream {
         let input_type = self
             .input_types
             .iter()
@@ -381,7 +390,7 @@ impl DmmfInputFieldExt for DmmfInputField {
             TypeLocation::Scalar => arity.wrap_type(
                 &ScalarType::try_from_str(&input_type.typ)
                     .unwrap()
-                    .to_tokens(),
+                    .to_tokens(bigint_type),
             ),
             TypeLocation::EnumTypes => {
                 let typ: TokenStream = input_type.typ.parse().unwrap();
@@ -395,7 +404,7 @@ impl DmmfInputFieldExt for DmmfInputField {
         }
     }
 
-    fn to_prisma_value(&self, var: &Ident) -> TokenStream {
+    fn to_prisma_value(&self, var: &Ident, bigint_type: BigIntType) -> TokenStream {
         let pv = quote!(::prisma_client_rust::PrismaValue);
 
         let input_type = self
@@ -411,7 +420,7 @@ impl DmmfInputFieldExt for DmmfInputField {
                 var,
                 ScalarType::try_from_str(&input_type.typ)
                     .unwrap()
-                    .to_prisma_value(var),
+                    .to_prisma_value(var, bigint_type),
             ),
             TypeLocation::EnumTypes => arity.wrap_pv(var, quote!(#pv::Enum(#var.to_string()))),
             TypeLocation::InputObjectTypes => {
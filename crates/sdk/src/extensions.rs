@@ -32,9 +32,9 @@ impl<'a> ModelExt<'a> for ModelWalker<'a> {
 }
 
 pub trait FieldExt<'a> {
-    fn type_tokens(self, prefix: &TokenStream) -> Option<TokenStream>;
+    fn type_tokens(self, prefix: &TokenStream, bigint_type: BigIntType) -> Option<TokenStream>;
 
-    fn type_prisma_value(self, var: &Ident) -> Option<TokenStream>;
+    fn type_prisma_value(self, var: &Ident, bigint_type: BigIntType) -> Option<TokenStream>;
 
     fn relation_methods(self) -> &'static [&'static str];
 
@@ -42,12 +42,13 @@ pub trait FieldExt<'a> {
 }
 
 impl<'a> FieldExt<'a> for FieldWalker<'a> {
-    fn type_tokens(self, prefix: &TokenStream) -> Option<TokenStream> {
+    fn type_tokens(self, prefix: &TokenStream, bigint_type: BigIntType) -> Option<TokenStream> {
         match self.refine() {
             RefinedFieldWalker::Scalar(scalar_field) => scalar_field.scalar_field_type().to_tokens(
                 prefix,
                 &self.ast_field().arity,
                 &self.db,
+                bigint_type,
             ),
             RefinedFieldWalker::Relation(relation_field) => {
                 let related_model_name_snake = snake_ident(relation_field.related_model().name());
@@ -61,9 +62,11 @@ impl<'a> FieldExt<'a> for FieldWalker<'a> {
         }
     }
 
-    fn type_prisma_value(self, var: &Ident) -> Option<TokenStream> {
+    fn type_prisma_value(self, var: &Ident, bigint_type: BigIntType) -> Option<TokenStream> {
         match self.refine() {
-            RefinedFieldWalker::Scalar(scalar_field) => scalar_field.type_prisma_value(var),
+            RefinedFieldWalker::Scalar(scalar_field) => {
+                scalar_field.type_prisma_value(var, bigint_type)
+            }
             RefinedFieldWalker::Relation(_) => None,
         }
     }
@@ -86,12 +89,14 @@ impl<'a> FieldExt<'a> for FieldWalker<'a> {
 }
 
 impl<'a> FieldExt<'a> for CompositeTypeFieldWalker<'a> {
-    fn type_tokens(self, prefix: &TokenStream) -> Option<TokenStream> {
-        self.r#type().to_tokens(prefix, &self.arity(), &self.db)
+    fn type_tokens(self, prefix: &TokenStream, bigint_type: BigIntType) -> Option<TokenStream> {
+        self.r#type()
+            .to_tokens(prefix, &self.arity(), &self.db, bigint_type)
     }
 
-    fn type_prisma_value(self, var: &Ident) -> Option<TokenStream> {
-        self.r#type().to_prisma_value(var, &self.arity())
+    fn type_prisma_value(self, var: &Ident, bigint_type: BigIntType) -> Option<TokenStream> {
+        self.r#type()
+            .to_prisma_value(var, &self.arity(), bigint_type)
     }
 
     fn relation_methods(self) -> &'static [&'static str] {
@@ -149,14 +154,14 @@ impl<'a> ScalarFieldWalkerExt for ScalarFieldWalker<'a> {
 }
 
 impl<'a> FieldExt<'a> for ScalarFieldWalker<'a> {
-    fn type_tokens(self, prefix: &TokenStream) -> Option<TokenStream> {
+    fn type_tokens(self, prefix: &TokenStream, bigint_type: BigIntType) -> Option<TokenStream> {
         self.scalar_field_type()
-            .to_tokens(prefix, &self.ast_field().arity, self.db)
+            .to_tokens(prefix, &self.ast_field().arity, self.db, bigint_type)
     }
 
-    fn type_prisma_value(self, var: &Ident) -> Option<TokenStream> {
+    fn type_prisma_value(self, var: &Ident, bigint_type: BigIntType) -> Option<TokenStream> {
         self.scalar_field_type()
-            .to_prisma_value(var, &self.ast_field().arity)
+            .to_prisma_value(var, &self.ast_field().arity, bigint_type)
     }
 
     fn relation_methods(self) -> &'static [&'static str] {
@@ -176,8 +181,14 @@ pub trait ScalarFieldTypeExt {
         prefix: &TokenStream,
         arity: &FieldArity,
         db: &ParserDatabase,
+        bigint_type: BigIntType,
+    ) -> Option<TokenStream>;
+    fn to_prisma_value(
+        &self,
+        var: &Ident,
+        arity: &FieldArity,
+        bigint_type: BigIntType,
     ) -> Option<TokenStream>;
-    fn to_prisma_value(&self, var: &Ident, arity: &FieldArity) -> Option<TokenStream>;
 }
 
 impl ScalarFieldTypeExt for ScalarFieldType {
@@ -186,13 +197,14 @@ impl ScalarFieldTypeExt for ScalarFieldType {
         prefix: &TokenStream,
         arity: &FieldArity,
         db: &ParserDatabase,
+        bigint_type: BigIntType,
     ) -> Option<TokenStream> {
         let base = match *self {
             Self::Enum(id) => {
                 let name = pascal_ident(db.walk(id).name());
                 quote!(#prefix #name)
             }
-            Self::BuiltInScalar(typ) => typ.to_tokens(),
+            Self::BuiltInScalar(typ) => typ.to_tokens(bigint_type),
             Self::Unsupported(_) => return None,
             Self::CompositeType(id) => {
                 let name = snake_ident(db.walk(id).name());
@@ -203,11 +215,16 @@ impl ScalarFieldTypeExt for ScalarFieldType {
         Some(arity.wrap_type(&base))
     }
 
-    fn to_prisma_value(&self, var: &Ident, arity: &FieldArity) -> Option<TokenStream> {
+    fn to_prisma_value(
+        &self,
+        var: &Ident,
+        arity: &FieldArity,
+        bigint_type: BigIntType,
+    ) -> Option<TokenStream> {
         let pv = quote!(::prisma_client_rust::PrismaValue);
 
         let scalar_converter = match self {
-            Self::BuiltInScalar(typ) => typ.to_prisma_value(&var),
+            Self::BuiltInScalar(typ) => typ.to_prisma_value(&var, bigint_type),
             Self::Enum(_) => quote!(#pv::Enum(#var.to_string())),
             Self::Unsupported(_) => return None,
             Self::CompositeType(_) => quote!(#pv::Object(vec![])),
@@ -217,19 +234,38 @@ impl ScalarFieldTypeExt for ScalarFieldType {
     }
 }
 
+/// The Rust type that `BigInt` fields are generated as.
+/// Prisma's query engine represents them as `i64`,
+/// so other types are converted with overflow checks when writing and reading values.
+/// Values that don't fit in an `i64` are sent as strings, which the engine rejects with a query error.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+pub enum BigIntType {
+    #[default]
+    #[serde(rename = "i64")]
+    I64,
+    #[serde(rename = "u64")]
+    U64,
+    #[serde(rename = "i128")]
+    I128,
+}
+
 pub trait ScalarTypeExt {
-    fn to_tokens(&self) -> TokenStream;
-    fn to_prisma_value(&self, var: &Ident) -> TokenStream;
+    fn to_tokens(&self, bigint_type: BigIntType) -> TokenStream;
+    fn to_prisma_value(&self, var: &Ident, bigint_type: BigIntType) -> TokenStream;
     fn to_dmmf_string(&self) -> String;
 }
 
 impl ScalarTypeExt for ScalarType {
-    fn to_tokens(&self) -> TokenStream {
+    fn to_tokens(&self, bigint_type: BigIntType) -> TokenStream {
         let pcr = quote!(::prisma_client_rust);
 
         match self {
             ScalarType::Int => quote!(i32),
-            ScalarType::BigInt => quote!(i64),
+            ScalarType::BigInt => match bigint_type {
+                BigIntType::I64 => quote!(i64),
+                BigIntType::U64 => quote!(u64),
+                BigIntType::I128 => quote!(i128),
+            },
             ScalarType::Float => quote!(f64),
             ScalarType::Decimal => quote!(#pcr::bigdecimal::BigDecimal),
             ScalarType::Boolean => quote!(bool),
@@ -246,17 +282,22 @@ impl ScalarTypeExt for ScalarType {
         }
     }
 
-    fn to_prisma_value(&self, var: &Ident) -> TokenStream {
+    fn to_prisma_value(&self, var: &Ident, bigint_type: BigIntType) -> TokenStream {
         let pcr = quote!(::prisma_client_rust);
         let v = quote!(#pcr::PrismaValue);
 
         match self {
             ScalarType::Int => quote!(#v::Int(#var as i64)),
-            ScalarType::BigInt => quote!(#v::BigInt(#var)),
+            ScalarType::BigInt => match bigint_type {
+                BigIntType::I64 => quote!(#v::BigInt(#var)),
+                _ => quote!(i64::try_from(#var)
+                    .map(#v::BigInt)
+                    .unwrap_or_else(|_| #v::String(#var.to_string()))),
+            },
             ScalarType::Float => {
                 quote!(#v::Float(<#pcr::bigdecimal::BigDecimal as #pcr::bigdecimal::FromPrimitive>::from_f64(#var).unwrap().normalized()))
             }
-            ScalarType::Decimal => quote!(#v::String(#var.to_string())),
+            ScalarType::Decimal => quote!(#v::Float(#var)),
             ScalarType::Boolean => quote!(#v::Boolean(#var)),
             ScalarType::String => quote!(#v::String(#var)),
             ScalarType::Json => quote!(#v::Json(#pcr::serde_json::to_string(&#var).unwrap())),
@@ -279,6 +320,7 @@ pub trait DmmfTypeReferenceExt {
         prefix: &TokenStream,
         arity: &FieldArity,
         db: &ParserDatabase,
+        bigint_type: BigIntType,
     ) -> Option<TokenStream>;
 }
 
@@ -288,11 +330,12 @@ impl DmmfTypeReferenceExt for DmmfTypeReference {
         prefix: &TokenStream,
         arity: &FieldArity,
         db: &ParserDatabase,
+        bigint_type: BigIntType,
     ) -> Option<TokenStream> {
         Some(match self.location {
             TypeLocation::Scalar => {
                 ScalarFieldType::BuiltInScalar(ScalarType::try_from_str(&self.typ).unwrap())
-                    .to_tokens(prefix, arity, db)?
+                    .to_tokens(prefix, arity, db, bigint_type)?
             }
             TypeLocation::EnumTypes => {
                 let enum_name_pascal = pascal_ident(&self.typ);
@@ -0,0 +1,81 @@
+use crate::db::*;
+use crate::utils::*;
+
+async fn create_posts(client: &PrismaClient) -> TestResult {
+    client
+        .post()
+        .create_many(vec![
+            post::create_unchecked("Hi from Prisma!".to_string(), true, vec![post::views::set(1)]),
+            post::create_unchecked("Hi from Prisma!".to_string(), true, vec![post::views::set(2)]),
+            post::create_unchecked("Hi from Prisma!".to_string(), false, vec![post::views::set(6)]),
+        ])
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn basic() -> TestResult {
+    let client = client().await;
+
+    create_posts(&client).await?;
+
+    let data = client
+        .post()
+        .aggregate(vec![])
+        .with(post::count_all())
+        .with(post::views::avg())
+        .with(post::views::sum())
+        .with(post::views::min())
+        .with(post::views::max())
+        .exec()
+        .await?;
+
+    assert_eq!(data._count.unwrap()._all, Some(3));
+    assert_eq!(data._avg.unwrap().views, Some(3.0));
+    assert_eq!(data._sum.unwrap().views, Some(9));
+    assert_eq!(data._min.unwrap().views, Some(1));
+    assert_eq!(data._max.unwrap().views, Some(6));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn where_() -> TestResult {
+    let client = client().await;
+
+    create_posts(&client).await?;
+
+    let data = client
+        .post()
+        .aggregate(vec![post::published::equals(true)])
+        .with(post::count_all())
+        .with(post::views::sum())
+        .exec()
+        .await?;
+
+    assert_eq!(data._count.unwrap()._all, Some(2));
+    assert_eq!(data._sum.unwrap().views, Some(3));
+    assert!(data._avg.is_none());
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn no_results() -> TestResult {
+    let client = client().await;
+
+    let data = client
+        .post()
+        .aggregate(vec![])
+        .with(post::count_all())
+        .with(post::views::max())
+        .exec()
+        .await?;
+
+    assert_eq!(data._count.unwrap()._all, Some(0));
+    assert_eq!(data._max.unwrap().views, None);
+
+    cleanup(client).await
+}
@@ -21,6 +21,26 @@ async fn tuple() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn isolation_level() -> TestResult {
+    let client = client().await;
+
+    let (brendan, oscar) = client
+        ._batch_with_isolation_level(
+            (
+                client.user().create("Brendan".to_string(), vec![]),
+                client.user().create("Oscar".to_string(), vec![]),
+            ),
+            TransactionIsolationLevel::Serializable,
+        )
+        .await?;
+
+    assert_eq!(&brendan.name, "Brendan");
+    assert_eq!(&oscar.name, "Oscar");
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn vec() -> TestResult {
     let client = client().await;
@@ -85,6 +105,41 @@ async fn vec_in_tuple() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn nested() -> TestResult {
+    let client = client().await;
+
+    let (groups, (brendan, pairs)) = client
+        ._batch((
+            vec![
+                vec![client.user().create("Oscar".to_string(), vec![])],
+                vec![],
+                vec![
+                    client.user().create("Tom".to_string(), vec![]),
+                    client.user().create("Tim".to_string(), vec![]),
+                ],
+            ],
+            (
+                client.user().create("Brendan".to_string(), vec![]),
+                vec![(
+                    client.user().create("Alice".to_string(), vec![]),
+                    client.user().count(vec![]),
+                )],
+            ),
+        ))
+        .await?;
+
+    assert_eq!(groups.len(), 3);
+    assert_eq!(&groups[0][0].name, "Oscar");
+    assert!(groups[1].is_empty());
+    assert_eq!(&groups[2][1].name, "Tim");
+    assert_eq!(&brendan.name, "Brendan");
+    assert_eq!(&pairs[0].0.name, "Alice");
+    assert_eq!(pairs[0].1, 5);
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn error() -> TestResult {
     let client = client().await;
@@ -373,3 +428,40 @@ async fn allows_empty() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn without_transaction() -> TestResult {
+    let client = client().await;
+
+    client
+        .user()
+        .create(
+            "Brendan".to_string(),
+            vec![user::id::set("abc".to_string())],
+        )
+        .exec()
+        .await?;
+
+    let results = client
+        ._batch_without_transaction(vec![
+            client.user().create(
+                "Brendan 2".to_string(),
+                vec![user::id::set("abc".to_string())],
+            ),
+            client
+                .user()
+                .create("Oscar".to_string(), vec![user::id::set("def".to_string())]),
+        ])
+        .await?;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0]
+        .as_ref()
+        .unwrap_err()
+        .is_prisma_error::<UniqueKeyViolation>());
+    assert_eq!(&results[1].as_ref().unwrap().name, "Oscar");
+
+    assert_eq!(client.user().count(vec![]).exec().await?, 2);
+
+    cleanup(client).await
+}
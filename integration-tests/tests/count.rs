@@ -91,3 +91,66 @@ async fn skip() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn distinct_and_field() -> TestResult {
+    let client = client().await;
+
+    create_posts(&client).await?;
+
+    client
+        .post()
+        .create(
+            "Hi from Rust!".to_string(),
+            true,
+            vec![post::desc::set(Some("Description".to_string()))],
+        )
+        .exec()
+        .await?;
+
+    let count = client
+        .post()
+        .count(vec![])
+        .distinct(vec![post::ScalarField::Title])
+        .exec()
+        .await?;
+    assert_eq!(count, 2);
+
+    let count = client
+        .post()
+        .count(vec![post::published::equals(true)])
+        .distinct(vec![post::ScalarField::Title, post::ScalarField::Published])
+        .exec()
+        .await?;
+    assert_eq!(count, 2);
+
+    let count = client
+        .post()
+        .count(vec![])
+        .field(post::ScalarField::Desc)
+        .exec()
+        .await?;
+    assert_eq!(count, 1);
+
+    let count = client
+        .post()
+        .count(vec![])
+        .distinct(vec![post::ScalarField::Desc])
+        .field(post::ScalarField::Desc)
+        .exec()
+        .await?;
+    assert_eq!(count, 1);
+
+    // pagination applies to the distinct combinations
+    let count = client
+        .post()
+        .count(vec![])
+        .distinct(vec![post::ScalarField::Title])
+        .order_by(post::title::order(SortOrder::Asc))
+        .take(1)
+        .exec()
+        .await?;
+    assert_eq!(count, 1);
+
+    cleanup(client).await
+}
@@ -139,3 +139,75 @@ async fn from_struct() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn nested_create_many() -> TestResult {
+    let client = client().await;
+
+    let user = client
+        .user()
+        .create(
+            "Brendan".to_string(),
+            vec![user::posts::create_many(
+                (0..3)
+                    .map(|i| {
+                        vec![
+                            post::title::set(format!("Post {i}")),
+                            post::published::set(true),
+                        ]
+                    })
+                    .collect(),
+            )],
+        )
+        .include(user::include!({ posts }))
+        .exec()
+        .await?;
+
+    assert_eq!(user.posts.len(), 3);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn generated_id() -> TestResult {
+    let client = client().await;
+
+    let user_id = user::id::generate();
+
+    let (user, post) = client
+        ._batch((
+            client
+                .user()
+                .create("Brendan".to_string(), vec![user::id::set(user_id.clone())]),
+            client.post().create(
+                "Hi from Prisma!".to_string(),
+                true,
+                vec![post::author::connect(user::id::equals(user_id.clone()))],
+            ),
+        ))
+        .await?;
+
+    assert_eq!(user.id, user_id);
+    assert_eq!(post.author_id, Some(user_id));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn generated_id_versions() -> TestResult {
+    let uuid = prisma_client_rust::ids::uuid_v7();
+
+    assert_eq!(uuid.len(), 36);
+    assert_eq!(&uuid[14..15], "7");
+    assert!("89ab".contains(&uuid[19..20]));
+
+    let cuid = prisma_client_rust::ids::cuid2();
+
+    assert_eq!(cuid.len(), 24);
+    assert!(cuid.starts_with(|c: char| c.is_ascii_lowercase()));
+    assert!(cuid
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+
+    Ok(())
+}
@@ -1,3 +1,7 @@
+use std::sync::{Arc, Mutex};
+
+use prisma_client_rust::{prisma_errors::query_engine::UniqueKeyViolation, QueryEvent};
+
 use crate::db::*;
 use crate::utils::*;
 
@@ -51,3 +55,136 @@ async fn skip_duplicates() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn split_by_bind_parameters() -> TestResult {
+    let events = Arc::new(Mutex::new(vec![]));
+
+    let client = PrismaClient::_builder()
+        .with_query_callback({
+            let events = events.clone();
+            move |event: &QueryEvent| events.lock().unwrap().push(event.operation.clone())
+        })
+        .build()
+        .await
+        .unwrap();
+
+    // more records than fit in one query under SQLite's limit of 999 bind parameters
+    let data = (0..1000)
+        .map(|i| post::create_unchecked(format!("Post {i}"), true, vec![]))
+        .collect();
+
+    let posts_count = client.post().create_many(data).exec().await?;
+    assert_eq!(posts_count, 1000);
+    assert_eq!(*events.lock().unwrap(), vec!["batch".to_string()]);
+
+    let posts_count = client.post().count(vec![]).exec().await?;
+    assert_eq!(posts_count, 1000);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn exec_in_batches() -> TestResult {
+    let events = Arc::new(Mutex::new(vec![]));
+
+    let client = PrismaClient::_builder()
+        .with_query_callback({
+            let events = events.clone();
+            move |event: &QueryEvent| events.lock().unwrap().push(event.operation.clone())
+        })
+        .build()
+        .await
+        .unwrap();
+
+    let data = (0..5)
+        .map(|i| post::create_unchecked(format!("Post {i}"), true, vec![]))
+        .collect();
+
+    let counts = client.post().create_many(data).exec_in_batches(2).await?;
+    assert_eq!(counts, vec![2, 2, 1]);
+
+    // each chunk is executed as its own query rather than in a batch
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec!["createManyPost".to_string(); 3]
+    );
+
+    let posts_count = client.post().count(vec![]).exec().await?;
+    assert_eq!(posts_count, 5);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn split_by_chunk_size() -> TestResult {
+    let events = Arc::new(Mutex::new(vec![]));
+
+    let client = PrismaClient::_builder()
+        .with_query_callback({
+            let events = events.clone();
+            move |event: &QueryEvent| events.lock().unwrap().push(event.query.clone())
+        })
+        .build()
+        .await
+        .unwrap();
+
+    let data = (0..5)
+        .map(|i| post::create_unchecked(format!("Post {i}"), true, vec![]))
+        .collect();
+
+    let posts_count = client.post().create_many(data).chunk_size(2).exec().await?;
+    assert_eq!(posts_count, 5);
+
+    // the records are split into 3 queries that are sent as one batch
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].matches("createManyPost").count(), 3);
+
+    let posts_count = client.post().count(vec![]).exec().await?;
+    assert_eq!(posts_count, 5);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn split_atomically() -> TestResult {
+    let client = client().await;
+
+    let data = (0..1000)
+        .map(|i| {
+            post::create_unchecked(
+                format!("Post {i}"),
+                true,
+                vec![post::id::set((i % 999).to_string())],
+            )
+        })
+        .collect();
+
+    // only the last query contains a duplicate id, so the earlier queries are rolled back
+    let error = client.post().create_many(data).exec().await.unwrap_err();
+    assert!(error.is_prisma_error::<UniqueKeyViolation>());
+
+    let posts_count = client.post().count(vec![]).exec().await?;
+    assert_eq!(posts_count, 0);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn split_in_batch() -> TestResult {
+    let client = client().await;
+
+    let data = (0..1000)
+        .map(|i| post::create_unchecked(format!("Post {i}"), true, vec![]))
+        .collect();
+
+    let (created, count) = client
+        ._batch((client.post().create_many(data), client.post().count(vec![])))
+        .await?;
+
+    assert_eq!(created, 1000);
+    assert_eq!(count, 1000);
+
+    cleanup(client).await
+}
@@ -68,3 +68,27 @@ async fn record_not_found() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn select() -> TestResult {
+    let client = client().await;
+
+    let post = client
+        .post()
+        .create("Hi from Prisma!".to_string(), false, vec![])
+        .exec()
+        .await?;
+
+    let deleted = client
+        .post()
+        .delete(post::id::equals(post.id.clone()))
+        .select(post::select!({ id title }))
+        .exec()
+        .await?;
+    assert_eq!(deleted.id, post.id);
+    assert_eq!(deleted.title, "Hi from Prisma!");
+
+    assert_eq!(client.post().count(vec![]).exec().await?, 0);
+
+    cleanup(client).await
+}
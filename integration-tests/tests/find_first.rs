@@ -1,4 +1,4 @@
-use prisma_client_rust::{and, not, or};
+use prisma_client_rust::{and, not, or, prisma_errors::query_engine::RecordRequiredButNotFound};
 
 use crate::{db::*, utils::*};
 
@@ -356,3 +356,58 @@ async fn list_wrapper_query_transformation() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn exists() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create("Test post".to_string(), true, vec![])
+        .exec()
+        .await?;
+
+    let exists = client
+        .post()
+        .find_first(vec![post::title::equals("Test post".to_string())])
+        .exists()
+        .await?;
+    assert!(exists);
+
+    let exists = client
+        .post()
+        .find_first(vec![post::published::equals(false)])
+        .exists()
+        .await?;
+    assert!(!exists);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn or_throw() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create("Test post".to_string(), true, vec![])
+        .exec()
+        .await?;
+
+    let found = client
+        .post()
+        .find_first(vec![post::published::equals(true)])
+        .exec_or_throw()
+        .await?;
+    assert_eq!(found.title, "Test post");
+
+    let error = client
+        .post()
+        .find_first(vec![post::published::equals(false)])
+        .exec_or_throw()
+        .await
+        .unwrap_err();
+    assert!(error.is_prisma_error::<RecordRequiredButNotFound>());
+
+    cleanup(client).await
+}
@@ -1,4 +1,6 @@
-use prisma_client_rust::or;
+use futures::TryStreamExt;
+use prisma_client_rust::{or, DynamicFilterError, QueryError};
+use serde_json::json;
 
 use crate::{db::*, utils::*};
 
@@ -142,6 +144,48 @@ async fn cursor() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn last() -> TestResult {
+    let client = client().await;
+
+    let mut posts = vec![];
+
+    for i in 1..=4 {
+        posts.push(
+            client
+                .post()
+                .create(format!("Foo {i}"), false, vec![])
+                .exec()
+                .await?,
+        );
+    }
+
+    let found = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .last(2)
+        .exec()
+        .await?;
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].title, "Foo 3".to_string());
+    assert_eq!(found[1].title, "Foo 4".to_string());
+
+    let found = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .cursor(post::id::equals(posts[2].id.clone()))
+        .last(2)
+        .exec()
+        .await?;
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].title, "Foo 2".to_string());
+    assert_eq!(found[1].title, "Foo 3".to_string());
+
+    cleanup(client).await
+}
+
 // From Spacedrive
 #[tokio::test]
 async fn cursor_order() -> TestResult {
@@ -273,6 +317,26 @@ async fn filtering_one_to_one_relation() -> TestResult {
     assert_eq!(users.len(), 1);
     assert_eq!(users[0].name, "Jamie");
 
+    let profiles = client
+        .profile()
+        .find_many(vec![profile::user::is(vec![user::name::equals(
+            "Oscar".to_string(),
+        )])])
+        .exec()
+        .await?;
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].bio, "Hello world, this is my bio.");
+
+    let profiles = client
+        .profile()
+        .find_many(vec![profile::user::is_not(vec![user::name::equals(
+            "Oscar".to_string(),
+        )])])
+        .exec()
+        .await?;
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].bio, "My very cool bio.");
+
     cleanup(client).await
 }
 
@@ -381,6 +445,24 @@ async fn filtering_one_to_many_relation() -> TestResult {
         .await?;
     assert_eq!(users.len(), 0);
 
+    let users = client
+        .user()
+        .find_many(vec![user::posts::some(vec![])])
+        .order_by(user::name::order(SortOrder::Asc))
+        .exec()
+        .await?;
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].name, "Brendan");
+    assert_eq!(users[1].name, "Oscar");
+
+    let users = client
+        .user()
+        .find_many(vec![user::posts::none(vec![])])
+        .exec()
+        .await?;
+    assert_eq!(users.len(), 1);
+    assert_eq!(users[0].name, "Jamie");
+
     cleanup(client).await
 }
 
@@ -477,3 +559,488 @@ async fn select() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn distinct() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(vec![
+            post::create_unchecked("Test post 1".to_string(), false, vec![]),
+            post::create_unchecked("Test post 1".to_string(), true, vec![]),
+            post::create_unchecked("Test post 2".to_string(), true, vec![]),
+        ])
+        .exec()
+        .await?;
+
+    let found = client
+        .post()
+        .find_many(vec![])
+        .distinct(vec![post::ScalarField::Title])
+        .order_by(post::title::order(SortOrder::Asc))
+        .exec()
+        .await?;
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].title, "Test post 1");
+    assert_eq!(found[1].title, "Test post 2");
+
+    let found = client
+        .post()
+        .find_many(vec![])
+        .distinct(vec![post::ScalarField::Title, post::ScalarField::Published])
+        .exec()
+        .await?;
+    assert_eq!(found.len(), 3);
+
+    let found = client
+        .post()
+        .find_first(vec![post::published::equals(true)])
+        .distinct(vec![post::ScalarField::Title])
+        .order_by(post::title::order(SortOrder::Desc))
+        .exec()
+        .await?
+        .unwrap();
+    assert_eq!(found.title, "Test post 2");
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn paginate() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(
+            (1..=5)
+                .map(|i| post::create_unchecked(format!("Test post {i}"), i % 2 == 0, vec![]))
+                .collect(),
+        )
+        .exec()
+        .await?;
+
+    let page = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .paginate(2, 2)
+        .await?;
+    assert_eq!(page.total, 5);
+    assert_eq!(page.page_count, 3);
+    assert_eq!(page.items.len(), 2);
+    assert_eq!(page.items[0].title, "Test post 3");
+    assert_eq!(page.items[1].title, "Test post 4");
+
+    let page = client
+        .post()
+        .find_many(vec![post::published::equals(true)])
+        .paginate(1, 10)
+        .await?;
+    assert_eq!(page.total, 2);
+    assert_eq!(page.page_count, 1);
+    assert_eq!(page.items.len(), 2);
+
+    let result = client.post().find_many(vec![]).paginate(0, 2).await;
+    assert!(matches!(result, Err(QueryError::Unsupported(_))));
+
+    let result = client.post().find_many(vec![]).paginate(1, 0).await;
+    assert!(matches!(result, Err(QueryError::Unsupported(_))));
+
+    // the total only counts the filters, so it wouldn't match a query that is already skipped
+    let result = client.post().find_many(vec![]).skip(1).paginate(1, 2).await;
+    assert!(matches!(result, Err(QueryError::Unsupported(_))));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn stream() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(
+            (1..=5)
+                .map(|i| post::create_unchecked(format!("Test post {i}"), false, vec![]))
+                .collect(),
+        )
+        .exec()
+        .await?;
+
+    let posts: Vec<_> = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .stream(2)
+        .try_collect()
+        .await?;
+    assert_eq!(posts.len(), 5);
+    assert_eq!(posts[0].title, "Test post 1");
+    assert_eq!(posts[4].title, "Test post 5");
+
+    let posts: Vec<_> = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .skip(1)
+        .take(3)
+        .stream(2)
+        .try_collect()
+        .await?;
+    assert_eq!(posts.len(), 3);
+    assert_eq!(posts[0].title, "Test post 2");
+    assert_eq!(posts[2].title, "Test post 4");
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn stream_with_deleted_records() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(
+            (1..=5)
+                .map(|i| post::create_unchecked(format!("Test post {i}"), false, vec![]))
+                .collect(),
+        )
+        .exec()
+        .await?;
+
+    let posts = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .stream(2);
+    futures::pin_mut!(posts);
+
+    let first = posts.try_next().await?.unwrap();
+    assert_eq!(first.title, "Test post 1");
+
+    // the next chunk continues after the last record rather than from an offset
+    client
+        .post()
+        .delete(post::id::equals(first.id))
+        .exec()
+        .await?;
+
+    let rest: Vec<_> = posts.try_collect().await?;
+    assert_eq!(rest.len(), 4);
+    assert_eq!(rest[0].title, "Test post 2");
+    assert_eq!(rest[1].title, "Test post 3");
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn stream_with_last() -> TestResult {
+    let client = client().await;
+
+    let result = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .last(2)
+        .exec_in_batches(2)
+        .await;
+
+    assert!(matches!(result, Err(QueryError::Unsupported(_))));
+
+    let result: Result<Vec<_>, _> = client
+        .post()
+        .find_many(vec![])
+        .last(2)
+        .stream(2)
+        .try_collect()
+        .await;
+
+    assert!(matches!(result, Err(QueryError::Unsupported(_))));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn exec_in_batches() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(
+            (1..=5)
+                .map(|i| post::create_unchecked(format!("Test post {i}"), false, vec![]))
+                .collect(),
+        )
+        .exec()
+        .await?;
+
+    let posts = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::title::order(SortOrder::Asc))
+        .exec_in_batches(2)
+        .await?;
+    assert_eq!(posts.len(), 5);
+    assert_eq!(posts[0].title, "Test post 1");
+    assert_eq!(posts[4].title, "Test post 5");
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn from_json() -> TestResult {
+    let client = client().await;
+
+    let user = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .exec()
+        .await?;
+
+    client
+        .post()
+        .create_many(vec![
+            post::create_unchecked(
+                "Post 1".to_string(),
+                true,
+                vec![post::author_id::set(Some(user.id.clone()))],
+            ),
+            post::create_unchecked("Post 2".to_string(), true, vec![]),
+            post::create_unchecked("Post 3".to_string(), false, vec![]),
+        ])
+        .exec()
+        .await?;
+
+    let filters = post::WhereParam::from_json(json!({
+        "published": true,
+        "OR": [
+            { "title": { "endsWith": "2" } },
+            { "author": { "is": { "name": "Brendan" } } }
+        ]
+    }))
+    .unwrap();
+
+    let posts = client
+        .post()
+        .find_many(filters)
+        .order_by(post::title::order(SortOrder::Asc))
+        .exec()
+        .await?;
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].title, "Post 1");
+    assert_eq!(posts[1].title, "Post 2");
+
+    assert!(matches!(
+        post::WhereParam::from_json(json!({ "name": "Post 1" })),
+        Err(DynamicFilterError::UnknownField { .. })
+    ));
+    assert!(matches!(
+        post::WhereParam::from_json(json!({ "title": { "has": "Post 1" } })),
+        Err(DynamicFilterError::UnknownFilter { .. })
+    ));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn nested_operators() -> TestResult {
+    use prisma_client_rust::operator::{and, not, or};
+
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(vec![
+            post::create_unchecked("Post 1".to_string(), true, vec![post::views::set(1)]),
+            post::create_unchecked("Post 2".to_string(), true, vec![post::views::set(10)]),
+            post::create_unchecked("Post 3".to_string(), false, vec![post::views::set(10)]),
+            post::create_unchecked("Post 4".to_string(), false, vec![post::views::set(1)]),
+        ])
+        .exec()
+        .await?;
+
+    // (published AND views > 5) OR (NOT published AND NOT views > 5)
+    let posts = client
+        .post()
+        .find_many(vec![or(vec![
+            and(vec![post::published::equals(true), post::views::gt(5)]),
+            and(vec![
+                post::published::equals(false),
+                not(vec![post::views::gt(5)]),
+            ]),
+        ])])
+        .order_by(post::title::order(SortOrder::Asc))
+        .exec()
+        .await?;
+    assert_eq!(posts.len(), 2);
+    assert_eq!(posts[0].title, "Post 2");
+    assert_eq!(posts[1].title, "Post 4");
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn where_after_construction() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(vec![
+            post::create_unchecked("Post 1".to_string(), true, vec![post::views::set(1)]),
+            post::create_unchecked("Post 2".to_string(), true, vec![post::views::set(10)]),
+            post::create_unchecked("Post 3".to_string(), false, vec![post::views::set(10)]),
+        ])
+        .exec()
+        .await?;
+
+    let only_published = true;
+    let min_views = Some(5);
+
+    let mut query = client.post().find_many(vec![]);
+
+    if only_published {
+        query = query.where_(post::published::equals(true));
+    }
+
+    let posts = query
+        .extend_where(min_views.map(post::views::gte))
+        .exec()
+        .await?;
+    assert_eq!(posts.len(), 1);
+    assert_eq!(posts[0].title, "Post 2");
+
+    let deleted = client
+        .post()
+        .delete_many(vec![])
+        .where_(post::published::equals(false))
+        .exec()
+        .await?;
+    assert_eq!(deleted, 1);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn clone_builder() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(
+            (1..=5)
+                .map(|i| post::create_unchecked(format!("Post {i}"), i % 2 == 0, vec![]))
+                .collect(),
+        )
+        .exec()
+        .await?;
+
+    let base = client
+        .post()
+        .find_many(vec![post::published::equals(false)])
+        .order_by(post::title::order(SortOrder::Asc));
+
+    let first_page = base.clone().take(2).exec().await?;
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page[0].title, "Post 1");
+
+    let all = base.exec().await?;
+    assert_eq!(all.len(), 3);
+
+    let count = client.post().count(vec![post::published::equals(true)]);
+    assert_eq!(count.clone().exec().await?, 2);
+    assert_eq!(count.take(1).exec().await?, 1);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn debug_builder() -> TestResult {
+    let client = client().await;
+
+    let query = client
+        .post()
+        .find_many(vec![post::title::equals("Post 1".to_string())])
+        .take(5);
+
+    let debug = format!("{query:?}");
+    assert!(debug.starts_with("FindMany"));
+    assert!(debug.contains("\"Post\""));
+    assert!(debug.contains("Post 1"));
+    assert!(debug.contains("take: Some(5)"));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn into_query_string() -> TestResult {
+    use prisma_client_rust::Query;
+
+    let client = client().await;
+
+    let query = client
+        .post()
+        .find_many(vec![post::title::equals("Post 1".to_string())])
+        .take(5)
+        .into_query_string();
+
+    assert!(query.starts_with(
+        "query {\n  findManyPost(where: { title: { equals: \"Post 1\" } }, take: 5) {\n    id\n"
+    ));
+    assert!(query.ends_with("  }\n}"));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn search_fields() -> TestResult {
+    let client = client().await;
+
+    client
+        .user()
+        .create(
+            "Brendan".to_string(),
+            vec![user::email::set(Some("brendan@example.com".to_string()))],
+        )
+        .exec()
+        .await?;
+    client
+        .user()
+        .create(
+            "Oscar".to_string(),
+            vec![user::email::set(Some("oscar@brendan.dev".to_string()))],
+        )
+        .exec()
+        .await?;
+    client
+        .user()
+        .create("Jamie".to_string(), vec![])
+        .exec()
+        .await?;
+
+    let fields = [user::ScalarField::Name, user::ScalarField::Email];
+
+    let found = client
+        .user()
+        .find_many(vec![user::search_fields(&fields, "end")])
+        .exec()
+        .await?;
+    assert_eq!(found.len(), 2);
+
+    let found = client
+        .user()
+        .find_many(vec![user::search_fields(&fields[..1], "end")])
+        .exec()
+        .await?;
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].name, "Brendan");
+
+    let found = client
+        .user()
+        .find_many(vec![user::search_fields(&fields, "example.com")])
+        .exec()
+        .await?;
+    assert_eq!(found.len(), 1);
+
+    cleanup(client).await
+}
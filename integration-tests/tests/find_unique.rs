@@ -1,3 +1,5 @@
+use prisma_client_rust::prisma_errors::query_engine::RecordRequiredButNotFound;
+
 use crate::{db::*, utils::*};
 
 #[tokio::test]
@@ -88,6 +90,44 @@ async fn compound() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn compound_primary_key() -> TestResult {
+    let client = client().await;
+
+    let user = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .exec()
+        .await?;
+
+    client
+        .file_path()
+        .create(
+            0,
+            "File Path".to_string(),
+            user::id::equals(user.id.clone()),
+            vec![],
+        )
+        .exec()
+        .await?;
+
+    let found = client
+        .file_path()
+        .find_unique(file_path::user_id_local_id(user.id.clone(), 0))
+        .exec()
+        .await?;
+    assert_eq!(found.map(|f| f.path), Some("File Path".to_string()));
+
+    let found = client
+        .file_path()
+        .find_unique(file_path::user_id_local_id(user.id, 1))
+        .exec()
+        .await?;
+    assert!(found.is_none());
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn no_match() -> TestResult {
     let client = client().await;
@@ -101,3 +141,31 @@ async fn no_match() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn or_throw() -> TestResult {
+    let client = client().await;
+
+    let post = client
+        .post()
+        .create("Test post".to_string(), true, vec![])
+        .exec()
+        .await?;
+
+    let found = client
+        .post()
+        .find_unique(post::id::equals(post.id.clone()))
+        .exec_or_throw()
+        .await?;
+    assert_eq!(found.id, post.id);
+
+    let error = client
+        .post()
+        .find_unique(post::id::equals("missing".to_string()))
+        .exec_or_throw()
+        .await
+        .unwrap_err();
+    assert!(error.is_prisma_error::<RecordRequiredButNotFound>());
+
+    cleanup(client).await
+}
@@ -0,0 +1,126 @@
+use prisma_client_rust::{or, AggregateFilter};
+
+use crate::db::*;
+use crate::utils::*;
+
+async fn create_posts(client: &PrismaClient) -> TestResult {
+    client
+        .post()
+        .create_many(vec![
+            post::create_unchecked("Hi from Prisma!".to_string(), true, vec![post::views::set(1)]),
+            post::create_unchecked("Hi from Prisma!".to_string(), true, vec![post::views::set(2)]),
+            post::create_unchecked("Hi from Prisma!".to_string(), false, vec![post::views::set(6)]),
+        ])
+        .exec()
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn basic() -> TestResult {
+    let client = client().await;
+
+    create_posts(&client).await?;
+
+    let groups = client
+        .post()
+        .group_by(vec![post::ScalarField::Published], vec![])
+        .with(post::count_all())
+        .with(post::views::sum())
+        .order_by(post::published::order(SortOrder::Asc))
+        .exec()
+        .await?;
+
+    assert_eq!(groups.len(), 2);
+
+    assert_eq!(groups[0].published, Some(false));
+    assert_eq!(groups[0]._count.as_ref().unwrap()._all, Some(1));
+    assert_eq!(groups[0]._sum.as_ref().unwrap().views, Some(6));
+    assert_eq!(groups[0].title, None);
+
+    assert_eq!(groups[1].published, Some(true));
+    assert_eq!(groups[1]._count.as_ref().unwrap()._all, Some(2));
+    assert_eq!(groups[1]._sum.as_ref().unwrap().views, Some(3));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn where_() -> TestResult {
+    let client = client().await;
+
+    create_posts(&client).await?;
+
+    let groups = client
+        .post()
+        .group_by(
+            vec![post::ScalarField::Published],
+            vec![post::views::gt(1)],
+        )
+        .with(post::count_all())
+        .order_by(post::published::order(SortOrder::Asc))
+        .exec()
+        .await?;
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0]._count.as_ref().unwrap()._all, Some(1));
+    assert_eq!(groups[1]._count.as_ref().unwrap()._all, Some(1));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn pagination() -> TestResult {
+    let client = client().await;
+
+    create_posts(&client).await?;
+
+    let groups = client
+        .post()
+        .group_by(vec![post::ScalarField::Views], vec![])
+        .order_by(post::views::order(SortOrder::Desc))
+        .skip(1)
+        .take(1)
+        .exec()
+        .await?;
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].views, Some(2));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn having() -> TestResult {
+    let client = client().await;
+
+    create_posts(&client).await?;
+
+    let groups = client
+        .post()
+        .group_by(vec![post::ScalarField::Published], vec![])
+        .with(post::count_all())
+        .having(post::id::having_count(AggregateFilter::Gt(1)))
+        .exec()
+        .await?;
+
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].published, Some(true));
+    assert_eq!(groups[0]._count.as_ref().unwrap()._all, Some(2));
+
+    let groups = client
+        .post()
+        .group_by(vec![post::ScalarField::Published], vec![])
+        .having(or![
+            post::views::having_sum(AggregateFilter::Gte(6)),
+            post::views::having_min(AggregateFilter::Equals(1)),
+        ])
+        .order_by(post::published::order(SortOrder::Asc))
+        .exec()
+        .await?;
+
+    assert_eq!(groups.len(), 2);
+
+    cleanup(client).await
+}
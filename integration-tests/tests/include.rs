@@ -142,3 +142,52 @@ async fn external_selection_args() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn many_relation_args_results() -> TestResult {
+    let client = client().await;
+
+    let user = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .exec()
+        .await?;
+
+    for (title, published) in [
+        ("Post 1", true),
+        ("Post 2", false),
+        ("Post 3", true),
+        ("Post 4", true),
+    ] {
+        client
+            .post()
+            .create(
+                title.to_string(),
+                published,
+                vec![post::author::connect(user::id::equals(user.id.clone()))],
+            )
+            .exec()
+            .await?;
+    }
+
+    let user = client
+        .user()
+        .find_unique(user::id::equals(user.id))
+        .include(user::include!({
+            posts(vec![post::published::equals(true)])
+                .order_by(post::title::order(SortOrder::Desc))
+                .take(2): include {
+                author
+            }
+        }))
+        .exec()
+        .await?
+        .unwrap();
+
+    assert_eq!(user.posts.len(), 2);
+    assert_eq!(user.posts[0].title, "Post 4");
+    assert_eq!(user.posts[1].title, "Post 3");
+    assert_eq!(user.posts[0].author.as_ref().unwrap().name, "Brendan");
+
+    cleanup(client).await
+}
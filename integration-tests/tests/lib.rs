@@ -21,6 +21,7 @@ async fn aaaa_run_migrations() -> TestResult {
     cleanup(client).await
 }
 
+mod aggregate;
 mod batch;
 // mod callbacks;
 mod count;
@@ -31,10 +32,15 @@ mod delete_many;
 mod find_first;
 mod find_many;
 mod find_unique;
+mod group_by;
 mod include;
+mod logging;
 mod mock;
 mod partial;
+mod ping;
+mod query_callback;
 mod raw;
+mod replica;
 mod select;
 mod specta;
 mod types;
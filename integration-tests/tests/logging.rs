@@ -0,0 +1,32 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use prisma_client_rust::QueryEvent;
+
+use crate::db::*;
+use crate::utils::*;
+
+#[tokio::test]
+async fn slow_query_callback() -> TestResult {
+    let slow = Arc::new(Mutex::new(vec![]));
+
+    let client = PrismaClient::_builder()
+        .with_slow_query_callback(Duration::ZERO, {
+            let slow = slow.clone();
+            move |event: &QueryEvent| slow.lock().unwrap().push(event.operation.clone())
+        })
+        .with_slow_query_callback(Duration::from_secs(60), |_: &QueryEvent| {
+            panic!("query wasn't slow")
+        })
+        .build()
+        .await
+        .unwrap();
+
+    client.user().find_many(vec![]).exec().await?;
+
+    assert_eq!(*slow.lock().unwrap(), vec!["findManyUser".to_string()]);
+
+    Ok(())
+}
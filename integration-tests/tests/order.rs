@@ -50,6 +50,15 @@ async fn _count() -> TestResult {
         .await?;
     assert_eq!(users_post_count_desc[0].name.as_str(), "User 3");
 
+    let users_post_count_desc = client
+        .user()
+        .find_many(vec![])
+        .order_by(user::posts::order_by_count(SortOrder::Desc))
+        .exec()
+        .await?;
+    assert_eq!(users_post_count_desc[0].name.as_str(), "User 3");
+    assert_eq!(users_post_count_desc[2].name.as_str(), "User 1");
+
     cleanup(client).await
 }
 
@@ -95,3 +104,47 @@ async fn relation() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn nulls() -> TestResult {
+    let client = client().await;
+
+    client
+        .post()
+        .create_many(vec![
+            post::create_unchecked(
+                "Post 1".to_string(),
+                false,
+                vec![post::desc::set(Some("Description".to_string()))],
+            ),
+            post::create_unchecked("Post 2".to_string(), false, vec![]),
+        ])
+        .exec()
+        .await?;
+
+    let posts = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::desc::order_with_nulls(
+            SortOrder::Asc,
+            NullsOrder::First,
+        ))
+        .exec()
+        .await?;
+    assert_eq!(posts[0].title, "Post 2");
+    assert_eq!(posts[1].title, "Post 1");
+
+    let posts = client
+        .post()
+        .find_many(vec![])
+        .order_by(post::desc::order_with_nulls(
+            SortOrder::Asc,
+            NullsOrder::Last,
+        ))
+        .exec()
+        .await?;
+    assert_eq!(posts[0].title, "Post 1");
+    assert_eq!(posts[1].title, "Post 2");
+
+    cleanup(client).await
+}
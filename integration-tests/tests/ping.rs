@@ -0,0 +1,39 @@
+use std::sync::{Arc, Mutex};
+
+use prisma_client_rust::QueryEvent;
+
+use crate::db::*;
+use crate::utils::*;
+
+#[tokio::test]
+async fn ping() -> TestResult {
+    let client = client().await;
+
+    client._ping().await?;
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn executes_query() -> TestResult {
+    let events = Arc::new(Mutex::new(vec![]));
+
+    let client = PrismaClient::_builder()
+        .with_query_callback({
+            let events = events.clone();
+            move |event: &QueryEvent| events.lock().unwrap().push(event.clone())
+        })
+        .build()
+        .await
+        .unwrap();
+
+    client._ping().await?;
+
+    let events = events.lock().unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].operation, "queryRaw");
+    assert!(events[0].success);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use std::sync::{Arc, Mutex};
+
+use prisma_client_rust::QueryEvent;
+
+use crate::db::*;
+use crate::utils::*;
+
+#[tokio::test]
+async fn query_callback() -> TestResult {
+    let events = Arc::new(Mutex::new(vec![]));
+
+    let client = PrismaClient::_builder()
+        .with_query_callback({
+            let events = events.clone();
+            move |event: &QueryEvent| events.lock().unwrap().push(event.clone())
+        })
+        .build()
+        .await
+        .unwrap();
+
+    client.user().find_many(vec![]).exec().await?;
+
+    let events = events.lock().unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].operation, "findManyUser");
+    assert!(events[0].query.contains("findManyUser"));
+    assert!(events[0].success);
+
+    Ok(())
+}
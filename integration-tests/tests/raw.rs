@@ -1,4 +1,6 @@
-use prisma_client_rust::{prisma_models::PrismaValue, raw};
+use prisma_client_rust::{
+    list_placeholders, prisma_models::PrismaValue, queries::QueryError, raw, Raw,
+};
 
 use crate::{db::*, utils::*};
 
@@ -27,6 +29,32 @@ async fn query_raw_model() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn query_raw_list() -> TestResult {
+    let client = client().await;
+
+    let mut ids = vec![];
+    for title in ["First", "Second", "Third"] {
+        let post = client
+            .post()
+            .create(title.to_string(), false, vec![])
+            .exec()
+            .await?;
+        ids.push(post.id);
+    }
+
+    let result: Vec<post::Data> = client
+        ._query_raw(Raw::new(
+            &format!("SELECT * FROM Post WHERE id IN ({})", list_placeholders(2)),
+            ids[..2].iter().cloned().map(PrismaValue::String).collect(),
+        ))
+        .exec()
+        .await?;
+    assert_eq!(result.len(), 2);
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn query_raw_no_result() -> TestResult {
     let client = client().await;
@@ -43,6 +71,39 @@ async fn query_raw_no_result() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn query_raw_deserialize_error() -> TestResult {
+    #[derive(serde::Deserialize)]
+    struct Row {
+        #[allow(dead_code)]
+        title: i32,
+    }
+
+    let client = client().await;
+
+    client
+        .post()
+        .create("My post title!".to_string(), false, vec![])
+        .exec()
+        .await?;
+
+    let error = client
+        ._query_raw::<Row>(raw!("SELECT title FROM Post"))
+        .exec()
+        .await
+        .unwrap_err();
+
+    match error {
+        QueryError::Deserialize(message) => {
+            assert!(message.starts_with("row 0:"));
+            assert!(message.contains("title: string"));
+        }
+        error => panic!("expected a deserialize error, got {error:?}"),
+    }
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn execute_raw() -> TestResult {
     let client = client().await;
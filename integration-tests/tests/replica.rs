@@ -0,0 +1,73 @@
+use crate::db::*;
+use crate::utils::*;
+
+/// A separate database is used as the replica so that reads can be told apart,
+/// since it never receives the primary's writes
+async fn replica_client() -> PrismaClient {
+    let replica_url = "file:replica.db".to_string();
+
+    let replica = PrismaClient::_builder()
+        .with_url(replica_url.clone())
+        .build()
+        .await
+        .unwrap();
+    replica._db_push().accept_data_loss().await.unwrap();
+    replica.user().delete_many(vec![]).exec().await.unwrap();
+
+    PrismaClient::_builder()
+        .with_replica_url(replica_url)
+        .build()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn reads_from_replica() -> TestResult {
+    let client = replica_client().await;
+
+    let user = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .exec()
+        .await?;
+
+    let from_replica = client
+        .user()
+        .find_unique(user::id::equals(user.id.clone()))
+        .exec()
+        .await?;
+    let from_primary = client
+        ._primary()
+        .user()
+        .find_unique(user::id::equals(user.id.clone()))
+        .exec()
+        .await?;
+
+    assert!(from_replica.is_none());
+    assert_eq!(from_primary.map(|u| u.id), Some(user.id));
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn transaction_reads_from_primary() -> TestResult {
+    let client = replica_client().await;
+
+    let user = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .exec()
+        .await?;
+
+    let found = client
+        ._transaction()
+        .run(|tx| {
+            let id = user.id.clone();
+            async move { tx.user().find_unique(user::id::equals(id)).exec().await }
+        })
+        .await?;
+
+    assert_eq!(found.map(|u| u.id), Some(user.id));
+
+    cleanup(client).await
+}
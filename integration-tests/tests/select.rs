@@ -167,3 +167,92 @@ async fn external_selection_arguments() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn relation_count() -> TestResult {
+    let client = client().await;
+
+    let user = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .exec()
+        .await?;
+
+    client
+        .post()
+        .create(
+            "Post".to_string(),
+            true,
+            vec![post::author::connect(user::id::equals(user.id.clone()))],
+        )
+        .exec()
+        .await?;
+
+    let data = client
+        .user()
+        .find_unique(user::id::equals(user.id.clone()))
+        .select(user::select!({
+            id
+            _count
+        }))
+        .exec()
+        .await?
+        .unwrap();
+
+    assert_eq!(data.id, user.id);
+    assert_eq!(data._count.posts, Some(1));
+    assert_eq!(data._count.favourite_posts, Some(0));
+
+    cleanup(client).await
+}
+
+user::select!(user_name { id name });
+
+fn describe_user(user: &user_name::Data) -> String {
+    format!("{} ({})", user.name, user.id)
+}
+
+#[tokio::test]
+async fn module_level_selection() -> TestResult {
+    let client = client().await;
+
+    let created = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .select(user_name::select())
+        .exec()
+        .await?;
+
+    let found = client
+        .user()
+        .find_many(vec![])
+        .select(user_name::select())
+        .exec()
+        .await?;
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(describe_user(&found[0]), describe_user(&created));
+
+    cleanup(client).await
+}
+
+user::include!(user_with_posts as UserWithPosts {
+    posts: select { id title }
+});
+
+#[tokio::test]
+async fn named_data_type() -> TestResult {
+    let client = client().await;
+
+    let user: UserWithPosts = client
+        .user()
+        .create("Brendan".to_string(), vec![])
+        .include(user_with_posts::include())
+        .exec()
+        .await?;
+
+    assert_eq!(user.name, "Brendan");
+    assert!(user.posts.is_empty());
+
+    cleanup(client).await
+}
@@ -1,6 +1,10 @@
-use std::time::Duration;
+use std::{cell::Cell, panic::AssertUnwindSafe, time::Duration};
 
-use prisma_client_rust::QueryError;
+use futures::FutureExt;
+use prisma_client_rust::{
+    prisma_errors::{query_engine::TransactionWriteConflict, KnownError},
+    QueryError, RetryableError,
+};
 
 use crate::db::*;
 use crate::utils::*;
@@ -108,6 +112,237 @@ async fn custom_error() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn panic() -> TestResult {
+    let client = client().await;
+
+    let result = AssertUnwindSafe(client._transaction().run(|client| async move {
+        let user = client
+            .user()
+            .create("brendan".to_string(), vec![])
+            .exec()
+            .await?;
+
+        if user.name == "brendan" {
+            panic!("transaction panicked");
+        }
+
+        Ok::<_, QueryError>(user)
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+    assert!(client.user().find_many(vec![]).exec().await?.is_empty());
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn nested() -> TestResult {
+    let client = client().await;
+
+    client
+        ._transaction()
+        .run(|client| async move {
+            let user = client
+                .user()
+                .create("brendan".to_string(), vec![])
+                .exec()
+                .await?;
+
+            let result = client
+                ._transaction()
+                .run(|client| async move {
+                    client
+                        .post()
+                        .create("test".to_string(), true, vec![])
+                        .exec()
+                        .await?;
+
+                    Err::<(), _>(QueryError::Deserialize("rollback".to_string()))
+                })
+                .await;
+            assert!(result.is_err());
+
+            Ok::<_, QueryError>(user)
+        })
+        .await?;
+
+    assert_eq!(client.user().count(vec![]).exec().await?, 1);
+    assert_eq!(client.post().count(vec![]).exec().await?, 0);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn nested_options() -> TestResult {
+    let client = client().await;
+
+    client
+        ._transaction()
+        .run(|client| async move {
+            let result = client
+                ._transaction()
+                .with_timeout(10_000)
+                .run(|client| async move { client.user().count(vec![]).exec().await })
+                .await;
+
+            assert!(matches!(result, Err(QueryError::Unsupported(_))));
+
+            Ok::<_, QueryError>(())
+        })
+        .await?;
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn read_only() -> TestResult {
+    let client = client().await;
+
+    let result = client
+        ._transaction()
+        .read_only()
+        .run(|client| async move {
+            client
+                .user()
+                .create("brendan".to_string(), vec![])
+                .exec()
+                .await
+        })
+        .await;
+
+    // only PostgreSQL and CockroachDB can make transactions read-only
+    if prisma_client_rust::PrismaClient::internals(&client)
+        .url()
+        .starts_with("postgres")
+    {
+        assert!(matches!(result, Err(QueryError::Execute(_))));
+    } else {
+        assert!(matches!(result, Err(QueryError::Unsupported(_))));
+    }
+
+    assert_eq!(client.user().count(vec![]).exec().await?, 0);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn retries() -> TestResult {
+    let client = client().await;
+
+    let attempts = &Cell::new(0);
+
+    let result = client
+        ._transaction()
+        .run_with_retries(3, |client| async move {
+            attempts.set(attempts.get() + 1);
+
+            client
+                .post()
+                .create(
+                    "test".to_string(),
+                    true,
+                    vec![post::author::connect(user::id::equals("".to_string()))],
+                )
+                .exec()
+                .await
+        })
+        .await;
+
+    // only write conflicts are retried
+    assert!(result.is_err());
+    assert_eq!(attempts.get(), 1);
+
+    let user = client
+        ._transaction()
+        .run_with_retries(3, |client| async move {
+            client
+                .user()
+                .create("brendan".to_string(), vec![])
+                .exec()
+                .await
+        })
+        .await?;
+    assert_eq!(user.name, "brendan");
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn retries_write_conflict() -> TestResult {
+    let client = client().await;
+
+    #[derive(Debug, thiserror::Error)]
+    enum TxError {
+        #[error("prisma error")]
+        Prisma(#[from] QueryError),
+    }
+
+    impl RetryableError for TxError {
+        fn is_write_conflict(&self) -> bool {
+            match self {
+                Self::Prisma(e) => e.is_write_conflict(),
+            }
+        }
+    }
+
+    fn write_conflict() -> TxError {
+        QueryError::Execute(KnownError::new(TransactionWriteConflict {}).into()).into()
+    }
+
+    let attempts = &Cell::new(0);
+
+    // the first attempt fails with the same error the engine returns for write conflicts
+    let result = client
+        ._transaction()
+        .run_with_retries(3, |client| async move {
+            attempts.set(attempts.get() + 1);
+
+            let user = client
+                .user()
+                .create("brendan".to_string(), vec![])
+                .exec()
+                .await?;
+
+            match attempts.get() {
+                1 => Err(write_conflict()),
+                _ => Ok(user),
+            }
+        })
+        .await;
+
+    assert!(result.is_ok());
+    assert_eq!(attempts.get(), 2);
+    assert_eq!(client.user().count(vec![]).exec().await?, 1);
+
+    attempts.set(0);
+
+    // nested transactions aren't retried
+    client
+        ._transaction()
+        .run(|client| async move {
+            let result = client
+                ._transaction()
+                .run_with_retries(3, |_| async move {
+                    attempts.set(attempts.get() + 1);
+
+                    Err::<(), _>(write_conflict())
+                })
+                .await;
+
+            assert!(result.is_err());
+
+            Ok::<_, QueryError>(())
+        })
+        .await?;
+
+    assert_eq!(attempts.get(), 1);
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn timeout() -> TestResult {
     let client = client().await;
@@ -22,3 +22,135 @@ async fn decimal() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn decimal_16_digits() -> TestResult {
+    let client = client().await;
+
+    // SQLite stores decimals as floats, so these have more significant digits than f64::DIGITS
+    // but can still be stored exactly
+    for (id, dec) in ["9007199254740992", "1234567890.123456"]
+        .into_iter()
+        .enumerate()
+    {
+        let dec = BigDecimal::from_str(dec).unwrap();
+
+        let record = client
+            .types()
+            .create(vec![
+                types::id::set(id as i32),
+                types::decimal::set(Some(dec.clone())),
+            ])
+            .exec()
+            .await?;
+
+        assert_eq!(record.decimal, Some(dec));
+    }
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn float() -> TestResult {
+    let client = client().await;
+
+    for (id, float) in [1234.5678, 1.0 / 3.0, -0.1, 1e-20].into_iter().enumerate() {
+        let record = client
+            .types()
+            .create(vec![types::id::set(id as i32), types::float_::set(float)])
+            .exec()
+            .await?;
+
+        assert_eq!(record.float_, float);
+
+        let found = client
+            .types()
+            .find_first(vec![types::id::equals(id as i32)])
+            .exec()
+            .await?
+            .unwrap();
+
+        assert_eq!(found.float_, float);
+    }
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn decimal_filters() -> TestResult {
+    let client = client().await;
+
+    let dec = BigDecimal::from_str("2.5").unwrap();
+
+    client
+        .types()
+        .create(vec![types::decimal::set(Some(dec.clone()))])
+        .exec()
+        .await?;
+
+    let found = client
+        .types()
+        .find_many(vec![
+            types::decimal::equals(Some(dec.clone())),
+            types::decimal::gt(BigDecimal::from_str("2.4").unwrap()),
+        ])
+        .order_by(types::decimal::order(SortOrder::Asc))
+        .exec()
+        .await?;
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].decimal, Some(dec));
+
+    let found = client
+        .types()
+        .find_many(vec![types::decimal::lt(
+            BigDecimal::from_str("2.5").unwrap(),
+        )])
+        .exec()
+        .await?;
+
+    assert!(found.is_empty());
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn bytes() -> TestResult {
+    let client = client().await;
+
+    let bytes = vec![0, 1, 2, 254, 255];
+
+    let record = client
+        .types()
+        .create(vec![types::bytes::set(Some(bytes.clone()))])
+        .exec()
+        .await?;
+
+    assert_eq!(record.bytes, Some(bytes.clone()));
+
+    let found = client
+        .types()
+        .find_many(vec![types::bytes::equals(Some(bytes.clone()))])
+        .exec()
+        .await?;
+
+    assert_eq!(found.len(), 1);
+
+    let found = client
+        .types()
+        .find_many(vec![types::bytes::in_vec(vec![bytes.clone(), vec![3]])])
+        .exec()
+        .await?;
+
+    assert_eq!(found.len(), 1);
+
+    let found = client
+        .types()
+        .find_many(vec![types::bytes::not(Some(bytes))])
+        .exec()
+        .await?;
+
+    assert!(found.is_empty());
+
+    cleanup(client).await
+}
@@ -132,6 +132,53 @@ async fn disconnect() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn set_relation() -> TestResult {
+    let client = client().await;
+
+    let user_id = create_user(&client).await?;
+
+    let mut post_ids = vec![];
+    for title in ["First", "Second", "Third"] {
+        let post = client
+            .post()
+            .create(title.to_string(), true, vec![])
+            .exec()
+            .await?;
+        post_ids.push(post.id);
+    }
+
+    let updated = client
+        .user()
+        .update(
+            user::id::equals(user_id.clone()),
+            vec![user::favourite_posts::connect(vec![
+                post::id::equals(post_ids[0].clone()),
+                post::id::equals(post_ids[1].clone()),
+            ])],
+        )
+        .with(user::favourite_posts::fetch(vec![]))
+        .exec()
+        .await?;
+    assert_eq!(updated.favourite_posts().unwrap().len(), 2);
+
+    let updated = client
+        .user()
+        .update(
+            user::id::equals(user_id.clone()),
+            vec![user::favourite_posts::set(vec![post::id::equals(
+                post_ids[2].clone(),
+            )])],
+        )
+        .with(user::favourite_posts::fetch(vec![]))
+        .exec()
+        .await?;
+    assert_eq!(updated.favourite_posts().unwrap().len(), 1);
+    assert_eq!(updated.favourite_posts().unwrap()[0].id, post_ids[2]);
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn unchecked() -> TestResult {
     let client = client().await;
@@ -167,6 +214,119 @@ async fn unchecked() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn connect_or_create() -> TestResult {
+    let client = client().await;
+
+    let post = client
+        .post()
+        .create("My post".to_string(), true, vec![])
+        .exec()
+        .await?;
+
+    let updated = client
+        .post()
+        .update(
+            post::id::equals(post.id.clone()),
+            vec![post::author::connect_or_create(
+                user::id::equals("brendan".to_string()),
+                vec![
+                    user::id::set("brendan".to_string()),
+                    user::name::set("Brendan".to_string()),
+                ],
+            )],
+        )
+        .exec()
+        .await?;
+    assert_eq!(updated.author_id.as_deref(), Some("brendan"));
+
+    let user = client
+        .user()
+        .update(
+            user::id::equals("brendan".to_string()),
+            vec![user::posts::connect_or_create(vec![
+                (
+                    post::id::equals(post.id.clone()),
+                    vec![
+                        post::title::set("Unused".to_string()),
+                        post::published::set(false),
+                    ],
+                ),
+                (
+                    post::id::equals("missing".to_string()),
+                    vec![
+                        post::title::set("New post".to_string()),
+                        post::published::set(false),
+                    ],
+                ),
+            ])],
+        )
+        .with(user::posts::fetch(vec![]))
+        .exec()
+        .await?;
+
+    let mut titles = user
+        .posts()
+        .unwrap()
+        .iter()
+        .map(|post| post.title.as_str())
+        .collect::<Vec<_>>();
+    titles.sort();
+    assert_eq!(titles, vec!["My post", "New post"]);
+
+    assert_eq!(client.user().count(vec![]).exec().await?, 1);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn nested_many() -> TestResult {
+    let client = client().await;
+
+    let user_id = create_user(&client).await?;
+
+    for title in ["Keep", "Rename", "Remove"] {
+        client
+            .post()
+            .create(
+                title.to_string(),
+                false,
+                vec![post::author::connect(user::id::equals(user_id.clone()))],
+            )
+            .exec()
+            .await?;
+    }
+
+    let user = client
+        .user()
+        .update(
+            user::id::equals(user_id.clone()),
+            vec![
+                user::posts::update_many(
+                    vec![post::title::equals("Rename".to_string())],
+                    vec![post::title::set("Renamed".to_string())],
+                ),
+                user::posts::delete_many(vec![post::title::equals("Remove".to_string())]),
+            ],
+        )
+        .with(user::posts::fetch(vec![]))
+        .exec()
+        .await?;
+
+    let mut titles = user
+        .posts()
+        .unwrap()
+        .iter()
+        .map(|post| post.title.as_str())
+        .collect::<Vec<_>>();
+    titles.sort();
+    assert_eq!(titles, vec!["Keep", "Renamed"]);
+
+    assert_eq!(client.post().count(vec![]).exec().await?, 2);
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn atomic() -> TestResult {
     let client = client().await;
@@ -192,6 +352,102 @@ async fn atomic() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn atomic_operators() -> TestResult {
+    let client = client().await;
+
+    let record = client
+        .types()
+        .create(vec![
+            types::integer::set(10),
+            types::float_::set(1.5),
+            types::decimal::set(Some(BigDecimal::from_str("4.5").unwrap())),
+        ])
+        .exec()
+        .await?;
+
+    let updated = client
+        .types()
+        .update(
+            types::id_string(record.id, "".to_string()),
+            vec![
+                types::integer::multiply(3),
+                types::float_::divide(0.5),
+                types::decimal::decrement(BigDecimal::from_str("0.5").unwrap()),
+            ],
+        )
+        .exec()
+        .await?;
+    assert_eq!(updated.integer, 30);
+    assert_eq!(updated.float_, 3.0);
+    assert_eq!(updated.decimal, Some(BigDecimal::from_str("4").unwrap()));
+
+    let updated = client
+        .types()
+        .update(
+            types::id_string(record.id, "".to_string()),
+            vec![types::integer::decrement(5), types::float_::increment(1.0)],
+        )
+        .exec()
+        .await?;
+    assert_eq!(updated.integer, 25);
+    assert_eq!(updated.float_, 4.0);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn update_if_version() -> TestResult {
+    let client = client().await;
+
+    let user_id = create_user(&client).await?;
+
+    let profile = client
+        .profile()
+        .create(
+            user::id::equals(user_id),
+            "Bio".to_string(),
+            "Australia".to_string(),
+            vec![],
+        )
+        .exec()
+        .await?;
+    assert_eq!(profile.version, 0);
+
+    let updated = client
+        .profile()
+        .update_if_version(
+            profile::id::equals(profile.id),
+            0,
+            vec![profile::bio::set("New bio".to_string())],
+        )
+        .await?;
+    assert_eq!(updated.bio, "New bio");
+    assert_eq!(updated.version, 1);
+
+    let error = client
+        .profile()
+        .update_if_version(
+            profile::id::equals(profile.id),
+            0,
+            vec![profile::bio::set("Stale bio".to_string())],
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(error, QueryError::StaleVersion));
+
+    let profile = client
+        .profile()
+        .find_unique(profile::id::equals(profile.id))
+        .exec()
+        .await?
+        .unwrap();
+    assert_eq!(profile.bio, "New bio");
+    assert_eq!(profile.version, 1);
+
+    cleanup(client).await
+}
+
 #[tokio::test]
 async fn record_not_found() -> TestResult {
     let client = client().await;
@@ -49,3 +49,121 @@ async fn query() -> TestResult {
 
     cleanup(client).await
 }
+
+#[tokio::test]
+async fn create_or_update() -> TestResult {
+    let client = client().await;
+
+    let user = client
+        .user()
+        .create_or_update(
+            user::id::equals("brendan".to_string()),
+            user::create(
+                "Brendan".to_string(),
+                vec![
+                    user::id::set("brendan".to_string()),
+                    user::email::set(Some("brendan@example.com".to_string())),
+                ],
+            ),
+            vec![],
+        )
+        .exec()
+        .await?;
+    assert_eq!(user.name, "Brendan");
+
+    let user = client
+        .user()
+        .create_or_update(
+            user::id::equals("brendan".to_string()),
+            user::create(
+                "Oscar".to_string(),
+                vec![
+                    user::id::set("brendan".to_string()),
+                    user::email::set(Some("oscar@example.com".to_string())),
+                ],
+            ),
+            vec![user::email::set(None)],
+        )
+        .exec()
+        .await?;
+    assert_eq!(user.name, "Oscar");
+    assert_eq!(user.email, None);
+
+    assert_eq!(client.user().count(vec![]).exec().await?, 1);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn many() -> TestResult {
+    let client = client().await;
+
+    client
+        .user()
+        .create("Brendan".to_string(), vec![user::id::set("0".to_string())])
+        .exec()
+        .await?;
+
+    let users = client
+        .user()
+        .upsert_many(
+            (0..5)
+                .map(|i| {
+                    (
+                        user::id::equals(i.to_string()),
+                        user::create(format!("User {i}"), vec![user::id::set(i.to_string())]),
+                        vec![user::name::set(format!("Updated {i}"))],
+                    )
+                })
+                .collect(),
+        )
+        .exec()
+        .await?;
+
+    let created = users
+        .iter()
+        .map(|outcome| outcome.is_created())
+        .collect::<Vec<_>>();
+    assert_eq!(created, vec![false, true, true, true, true]);
+
+    let names = users
+        .iter()
+        .map(|outcome| outcome.data().name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec!["Updated 0", "User 1", "User 2", "User 3", "User 4"]
+    );
+
+    assert_eq!(client.user().count(vec![]).exec().await?, 5);
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn many_atomic() -> TestResult {
+    let client = client().await;
+
+    let result = client
+        .user()
+        .upsert_many(vec![
+            (
+                user::id::equals("0".to_string()),
+                user::create("User 0".to_string(), vec![user::id::set("0".to_string())]),
+                vec![],
+            ),
+            (
+                user::id::equals("1".to_string()),
+                // conflicts with the record created by the first upsert
+                user::create("User 1".to_string(), vec![user::id::set("0".to_string())]),
+                vec![],
+            ),
+        ])
+        .exec()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(client.user().count(vec![]).exec().await?, 0);
+
+    cleanup(client).await
+}
@@ -280,6 +280,59 @@ async fn nested_with() -> TestResult {
     cleanup(client).await
 }
 
+#[tokio::test]
+async fn relation_count() -> TestResult {
+    let client = client().await;
+
+    let user_id = setup(&client).await?;
+
+    let user = client
+        .user()
+        .find_unique(user::id::equals(user_id.clone()))
+        .with(user::posts::count())
+        .with(user::favourite_posts::count())
+        .exec()
+        .await?
+        .unwrap();
+    assert!(user.posts().is_err());
+
+    let count = user._count.unwrap();
+    assert_eq!(count.posts, Some(4));
+    assert_eq!(count.favourite_posts, Some(0));
+
+    let user = client
+        .user()
+        .find_unique(user::id::equals(user_id.clone()))
+        .exec()
+        .await?
+        .unwrap();
+    assert!(user._count.is_none());
+
+    cleanup(client).await
+}
+
+#[tokio::test]
+async fn filtered_relation_count() -> TestResult {
+    let client = client().await;
+
+    let user_id = setup(&client).await?;
+
+    let user = client
+        .user()
+        .find_unique(user::id::equals(user_id.clone()))
+        .with(user::posts::count_where(vec![post::published::equals(true)]))
+        .with(user::favourite_posts::count())
+        .exec()
+        .await?
+        .unwrap();
+
+    let count = user._count.unwrap();
+    assert_eq!(count.posts, Some(2));
+    assert_eq!(count.favourite_posts, Some(0));
+
+    cleanup(client).await
+}
+
 // TODO: Nested create
 
 // #[tokio::test]